@@ -0,0 +1,781 @@
+use anyhow::bail;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    compare::{Comparison, Operand},
+    condition::Condition,
+    effect::Effect,
+    phase::PhaseWarning,
+    reference::{AbilityOwner, AbilityRef},
+    shop::{PriceChange, ShopEffect},
+    summon::SummonSpec,
+    token::{attribute::EntityType, percent::Percent},
+    trigger::EffectTrigger,
+    usage::UsageLimit,
+    value::{Amount, Value},
+    SAPText,
+};
+
+/// A numeric field of an [`Ability`] and its value at each level.
+/// * [`Percent`]s are given in basis points so fractional percents are kept.
+/// * Amounts derived from [`Effect::entities`] are not included. They follow their entity.
+/// - ex. `effects[0].entities[0]` -> `[2, 4, 6]`
+/// - ex. `effects[0].entities[0]` of `12.5% attack` -> `[1250, 2500, 3750]`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LevelValue {
+    /// Path to field in [`Ability::effects`].
+    pub field: String,
+    /// Value of field at each level.
+    pub levels: Vec<i32>,
+}
+
+impl LevelValue {
+    /// Check if value changes between levels.
+    pub fn is_scaled(&self) -> bool {
+        self.levels.windows(2).any(|vals| vals[0] != vals[1])
+    }
+}
+
+/// A Super Auto Pets ability scaled by level.
+/// - ex. `Faint` -> `Give one random friend +2 attack and +1 health.`, `... +4 attack and +2 health.`, ...
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ability<'src> {
+    /// Ability trigger.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub trigger: Option<EffectTrigger<'src>>,
+    /// Effects at level 1.
+    /// * Numeric fields at other levels are given by [`Ability::values`]. See [`Ability::at_level`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub effects: Vec<Effect<'src>>,
+    /// Numeric fields of [`Ability::effects`] at each level.
+    pub values: Vec<LevelValue>,
+}
+
+/// Item with numeric fields that may change with level.
+trait LevelFields {
+    /// Map each numeric field in a fixed order.
+    ///
+    /// ### Params
+    /// * `path`
+    ///     * Path to item.
+    /// * `f`
+    ///     * Closure taking the path to a field and its value and returning its new value.
+    fn map_numbers(&mut self, path: &str, f: &mut dyn FnMut(&str, i32) -> i32);
+}
+
+impl LevelFields for i32 {
+    fn map_numbers(&mut self, path: &str, f: &mut dyn FnMut(&str, i32) -> i32) {
+        *self = f(path, *self)
+    }
+}
+
+impl LevelFields for usize {
+    fn map_numbers(&mut self, path: &str, f: &mut dyn FnMut(&str, i32) -> i32) {
+        let value = i32::try_from(*self).unwrap_or(i32::MAX);
+        *self = usize::try_from(f(path, value)).unwrap_or_default()
+    }
+}
+
+impl LevelFields for Percent {
+    fn map_numbers(&mut self, path: &str, f: &mut dyn FnMut(&str, i32) -> i32) {
+        *self = Percent::from_basis_points(f(path, self.basis_points()))
+    }
+}
+
+impl<T: LevelFields> LevelFields for Option<T> {
+    fn map_numbers(&mut self, path: &str, f: &mut dyn FnMut(&str, i32) -> i32) {
+        if let Some(item) = self {
+            item.map_numbers(path, f)
+        }
+    }
+}
+
+impl<T: LevelFields> LevelFields for Box<T> {
+    fn map_numbers(&mut self, path: &str, f: &mut dyn FnMut(&str, i32) -> i32) {
+        self.as_mut().map_numbers(path, f)
+    }
+}
+
+impl<T: LevelFields> LevelFields for Vec<T> {
+    fn map_numbers(&mut self, path: &str, f: &mut dyn FnMut(&str, i32) -> i32) {
+        for (i, item) in self.iter_mut().enumerate() {
+            item.map_numbers(&format!("{path}[{i}]"), f)
+        }
+    }
+}
+
+impl<'src> LevelFields for EntityType<'src> {
+    fn map_numbers(&mut self, path: &str, f: &mut dyn FnMut(&str, i32) -> i32) {
+        match self {
+            EntityType::AttackPercent(perc)
+            | EntityType::HealthPercent(perc)
+            | EntityType::DamagePercent(perc)
+            | EntityType::GoldPercent(perc)
+            | EntityType::TrumpetPercent(perc) => perc.map_numbers(path, f),
+            entity => entity.map_value(|value| f(path, value)),
+        }
+    }
+}
+
+impl<'src> LevelFields for EffectTrigger<'src> {
+    fn map_numbers(&mut self, path: &str, f: &mut dyn FnMut(&str, i32) -> i32) {
+        self.number.map_numbers(&format!("{path}.number"), f);
+        self.entity.map_numbers(&format!("{path}.entity"), f);
+    }
+}
+
+impl LevelFields for Operand {
+    fn map_numbers(&mut self, path: &str, f: &mut dyn FnMut(&str, i32) -> i32) {
+        if let Operand::Number(num) = self {
+            num.map_numbers(path, f)
+        }
+    }
+}
+
+impl<'src> LevelFields for Comparison<'src> {
+    fn map_numbers(&mut self, path: &str, f: &mut dyn FnMut(&str, i32) -> i32) {
+        self.lhs.map_numbers(&format!("{path}.lhs"), f);
+        self.rhs.map_numbers(&format!("{path}.rhs"), f);
+    }
+}
+
+impl<'src> LevelFields for Condition<'src> {
+    fn map_numbers(&mut self, path: &str, f: &mut dyn FnMut(&str, i32) -> i32) {
+        match self {
+            Condition::Predicate(trigger) => trigger.map_numbers(path, f),
+            Condition::Compare(cmp) => cmp.map_numbers(path, f),
+            Condition::And(conds) | Condition::Or(conds) => conds.map_numbers(path, f),
+            Condition::Not(cond) => cond.map_numbers(path, f),
+        }
+    }
+}
+
+impl<'src> LevelFields for Value<'src> {
+    fn map_numbers(&mut self, path: &str, f: &mut dyn FnMut(&str, i32) -> i32) {
+        match self {
+            Value::Literal(num) => num.map_numbers(path, f),
+            Value::PercentOf {
+                percent, holder, ..
+            } => {
+                percent.map_numbers(&format!("{path}.percent"), f);
+                holder.map_numbers(&format!("{path}.holder"), f);
+            }
+            Value::Times(value, count) => {
+                value.map_numbers(&format!("{path}.0"), f);
+                count.map_numbers(&format!("{path}.1"), f);
+            }
+            Value::CountOf(trigger) => trigger.map_numbers(path, f),
+            Value::Sum(values) => values.map_numbers(path, f),
+        }
+    }
+}
+
+impl<'src> LevelFields for Amount<'src> {
+    fn map_numbers(&mut self, path: &str, f: &mut dyn FnMut(&str, i32) -> i32) {
+        self.value.map_numbers(&format!("{path}.value"), f)
+    }
+}
+
+impl<'src> LevelFields for SummonSpec<'src> {
+    fn map_numbers(&mut self, path: &str, f: &mut dyn FnMut(&str, i32) -> i32) {
+        self.count.map_numbers(&format!("{path}.count"), f);
+        self.pet.map_numbers(&format!("{path}.pet"), f);
+        self.attack.map_numbers(&format!("{path}.attack"), f);
+        self.health.map_numbers(&format!("{path}.health"), f);
+        self.level.map_numbers(&format!("{path}.level"), f);
+        self.inherits.map_numbers(&format!("{path}.inherits"), f);
+    }
+}
+
+impl<'src> LevelFields for ShopEffect<'src> {
+    fn map_numbers(&mut self, path: &str, f: &mut dyn FnMut(&str, i32) -> i32) {
+        self.count.map_numbers(&format!("{path}.count"), f);
+        if let Some(PriceChange::Discount(gold)) = &mut self.price_change {
+            gold.map_numbers(&format!("{path}.price_change"), f);
+        }
+        self.item.map_numbers(&format!("{path}.item"), f);
+    }
+}
+
+impl<'src> LevelFields for AbilityRef<'src> {
+    fn map_numbers(&mut self, path: &str, f: &mut dyn FnMut(&str, i32) -> i32) {
+        if let AbilityOwner::Pet(pet) = &mut self.owner {
+            pet.map_numbers(&format!("{path}.owner"), f);
+        }
+        self.level.map_numbers(&format!("{path}.level"), f);
+        self.count.map_numbers(&format!("{path}.count"), f);
+    }
+}
+
+impl LevelFields for UsageLimit {
+    fn map_numbers(&mut self, path: &str, f: &mut dyn FnMut(&str, i32) -> i32) {
+        self.count.map_numbers(&format!("{path}.count"), f)
+    }
+}
+
+impl<'src> LevelFields for Effect<'src> {
+    fn map_numbers(&mut self, path: &str, f: &mut dyn FnMut(&str, i32) -> i32) {
+        self.trigger.map_numbers(&format!("{path}.trigger"), f);
+        self.cond_trigger
+            .map_numbers(&format!("{path}.cond_trigger"), f);
+        self.condition.map_numbers(&format!("{path}.condition"), f);
+        self.target_count
+            .map_numbers(&format!("{path}.target_count"), f);
+        // Derived amounts are rebuilt from their entities rather than mapped.
+        let num_derived = self.derived_amounts().len();
+        self.entities.map_numbers(&format!("{path}.entities"), f);
+        for (i, amount) in self.amounts.iter_mut().enumerate().skip(num_derived) {
+            amount.map_numbers(&format!("{path}.amounts[{i}]"), f)
+        }
+        self.amounts.splice(..num_derived, self.derived_amounts());
+        self.summon.map_numbers(&format!("{path}.summon"), f);
+        self.shop.map_numbers(&format!("{path}.shop"), f);
+        self.ability_ref
+            .map_numbers(&format!("{path}.ability_ref"), f);
+        self.comparisons
+            .map_numbers(&format!("{path}.comparisons"), f);
+        self.excluded.map_numbers(&format!("{path}.excluded"), f);
+        self.usage.map_numbers(&format!("{path}.usage"), f);
+        #[allow(deprecated)]
+        {
+            self.uses = self.usage.map(|usage| usage.count);
+        }
+    }
+}
+
+/// Macro to collect fields that differ between two [`Effect`]s.
+macro_rules! diff_effect_fields {
+    ($diffs:ident, $idx:expr, $effect:expr, $other:expr, [$($field:ident),+]) => {
+        $(
+            if $effect.$field != $other.$field {
+                $diffs.push(format!(
+                    "  effects[{}].{}: {:?} != {:?}",
+                    $idx,
+                    stringify!($field),
+                    $effect.$field,
+                    $other.$field
+                ));
+            }
+        )+
+    };
+}
+
+/// Remove a plural `s` from the name of an item.
+/// - ex. `Rams` -> `Ram`
+fn singularize(entity: &mut EntityType) {
+    if let EntityType::Pet {
+        name: Some(name), ..
+    }
+    | EntityType::Food {
+        name: Some(name), ..
+    }
+    | EntityType::Perk {
+        name: Some(name), ..
+    }
+    | EntityType::Ailment {
+        name: Some(name), ..
+    } = entity
+    {
+        let plural = *name;
+        *name = plural.strip_suffix('s').unwrap_or(plural)
+    }
+}
+
+/// Get the structure of effects with all numeric fields set to zero.
+/// * Item names are singular so levels affecting one or more items have the same structure. See [`singularize`].
+///     * ex. `Summon one 2/2 Ram.` and `Summon two 4/4 Rams.`
+fn effects_shape<'src>(effects: &[Effect<'src>]) -> Vec<Effect<'src>> {
+    let mut shape = effects.to_vec();
+    shape.map_numbers("effects", &mut |_, _| 0);
+    for effect in shape.iter_mut() {
+        let summon = effect.summon.as_mut();
+        let owner =
+            effect
+                .ability_ref
+                .as_mut()
+                .and_then(|ability_ref| match &mut ability_ref.owner {
+                    AbilityOwner::Pet(pet) => Some(pet),
+                    _ => None,
+                });
+        effect
+            .entities
+            .iter_mut()
+            .chain(effect.excluded.iter_mut())
+            .chain(effect.shop.as_mut().and_then(|shop| shop.item.as_mut()))
+            .chain(owner)
+            .chain(
+                summon
+                    .into_iter()
+                    .flat_map(|summon| summon.pet.iter_mut().chain(summon.inherits.iter_mut())),
+            )
+            .for_each(singularize);
+    }
+    shape
+}
+
+/// Get all numeric fields of effects and their paths.
+fn effects_numbers(effects: &[Effect]) -> Vec<(String, i32)> {
+    let mut numbers = vec![];
+    effects.to_vec().map_numbers("effects", &mut |path, value| {
+        numbers.push((path.to_owned(), value));
+        value
+    });
+    numbers
+}
+
+/// Describe structural differences between two sets of effects.
+///
+/// ### Params
+/// * `shape`
+///     * Structure of first set of effects. See [`effects_shape`].
+/// * `other`
+///     * Structure of other set of effects.
+///
+/// ### Returns
+/// * One line per differing field.
+fn diff_shapes(shape: &[Effect], other: &[Effect]) -> Vec<String> {
+    let mut diffs = vec![];
+    if shape.len() != other.len() {
+        diffs.push(format!(
+            "  number of effects: {} != {}",
+            shape.len(),
+            other.len()
+        ));
+    }
+    for (i, (effect, other_effect)) in shape.iter().zip(other).enumerate() {
+        diff_effect_fields!(
+            diffs,
+            i,
+            effect,
+            other_effect,
+            [
+                trigger,
+                cond_trigger,
+                condition,
+                target,
+                target_count,
+                entities,
+                amounts,
+                summon,
+                shop,
+                ability_ref,
+                comparisons,
+                excluded,
+                position,
+                action,
+                usage,
+                duration
+            ]
+        );
+    }
+    diffs
+}
+
+impl<'src> Ability<'src> {
+    /// Create a new ability from its trigger text and the effect text at each level.
+    /// * Each level must only differ in numbers.
+    ///
+    /// ### Params
+    /// * `trigger`
+    ///     * Trigger text.
+    ///     * Empty if no trigger.
+    /// * `levels`
+    ///     * Effect text at each level.
+    ///
+    /// ### Returns
+    /// * [`Ability`] or error if levels differ in structure.
+    ///
+    /// ```
+    /// use saplex::ability::Ability;
+    ///
+    /// let ability = Ability::new(
+    ///     "Faint",
+    ///     &[
+    ///         "Give one random friend +2 attack and +1 health.",
+    ///         "Give one random friend +4 attack and +2 health.",
+    ///         "Give one random friend +6 attack and +3 health.",
+    ///     ],
+    /// )
+    /// .unwrap();
+    ///
+    /// let scaled: Vec<&[i32]> = ability
+    ///     .values
+    ///     .iter()
+    ///     .filter(|value| value.is_scaled())
+    ///     .map(|value| value.levels.as_slice())
+    ///     .collect();
+    /// assert_eq!(scaled, [[2, 4, 6], [1, 2, 3]]);
+    ///
+    /// let invalid = Ability::new(
+    ///     "Faint",
+    ///     &["Gain +1 attack.", "Gain +2 health.", "Gain +3 health."],
+    /// );
+    /// assert!(invalid.is_err());
+    /// ```
+    pub fn new(trigger: &'src str, levels: &[&'src str]) -> anyhow::Result<Self> {
+        let mut abilities = Self::from_triggers(trigger, levels)?;
+        if abilities.len() != 1 {
+            bail!(
+                "Expected one trigger in {trigger:?} but found {}.",
+                abilities.len()
+            )
+        }
+        Ok(abilities.remove(0))
+    }
+
+    /// Parse abilities from a single `Trigger -> Effect` line.
+    /// * Trigger and effect text are separated by the first `→`, `->`, or `:`.
+    /// * One [`Ability`] is created per trigger.
+    ///     * ex. `End turn & Start of battle: ...` -> `End turn: ...` and `Start of battle: ...`
+    ///
+    /// ### Params
+    /// * `line`
+    ///     * Ability text.
+    ///
+    /// ### Returns
+    /// * One or more [`Ability`]s with a single level.
+    ///
+    /// ```
+    /// use saplex::{ability::Ability, token::ActionType};
+    ///
+    /// let abilities = Ability::parse("End turn & Start of battle: Gain +1 attack.").unwrap();
+    /// assert_eq!(abilities.len(), 2);
+    /// assert!(abilities
+    ///     .iter()
+    ///     .all(|ability| ability.effects[0].action == Some(ActionType::Gain)));
+    /// ```
+    pub fn parse(line: &'src str) -> anyhow::Result<Vec<Self>> {
+        let Some((trigger, effect)) = ["→", "->", ":"]
+            .into_iter()
+            .filter_map(|sep| line.find(sep).map(|idx| (idx, sep)))
+            .min_by_key(|(idx, _)| *idx)
+            .map(|(idx, sep)| (&line[..idx], &line[idx + sep.len()..]))
+        else {
+            bail!("No separator (→, ->, or :) between trigger and effect in {line:?}.")
+        };
+        Self::from_triggers(trigger, &[effect.trim()])
+    }
+
+    /// Create one ability per trigger from trigger text and the effect text at each level.
+    ///
+    /// ### Params
+    /// * `trigger`
+    ///     * Trigger text.
+    ///     * Empty if no trigger.
+    /// * `levels`
+    ///     * Effect text at each level.
+    ///
+    /// ### Returns
+    /// * One or more [`Ability`]s or error if levels differ in structure.
+    pub(crate) fn from_triggers(
+        trigger: &'src str,
+        levels: &[&'src str],
+    ) -> anyhow::Result<Vec<Self>> {
+        let trigger = trigger.trim();
+        if trigger.is_empty() {
+            return Ok(vec![Self::from_trigger(None, levels)?]);
+        }
+
+        let triggers: Vec<EffectTrigger> = SAPText::new(trigger).tokenize()?.try_into()?;
+        triggers
+            .into_iter()
+            .map(|trigger| Self::from_trigger(Some(trigger), levels))
+            .collect()
+    }
+
+    /// Create a new ability from a parsed trigger and the effect text at each level.
+    ///
+    /// ### Params
+    /// * `trigger`
+    ///     * Optional [`EffectTrigger`]
+    /// * `levels`
+    ///     * Effect text at each level.
+    ///
+    /// ### Returns
+    /// * [`Ability`] or error if levels differ in structure.
+    fn from_trigger(
+        trigger: Option<EffectTrigger<'src>>,
+        levels: &[&'src str],
+    ) -> anyhow::Result<Self> {
+        let level_effects = levels.iter().map(|txt| {
+            let tokens = SAPText::new(txt).tokenize()?;
+            Effect::new(trigger.clone(), &tokens)
+        });
+        Self::from_level_effects(trigger.clone(), level_effects)
+    }
+
+    /// Create a new ability from the effects at each level.
+    ///
+    /// ### Params
+    /// * `trigger`
+    ///     * Optional [`EffectTrigger`]
+    /// * `level_effects`
+    ///     * Effects at each level or error if effects could not be created.
+    ///
+    /// ### Returns
+    /// * [`Ability`] or error if levels differ in structure.
+    pub(crate) fn from_level_effects(
+        trigger: Option<EffectTrigger<'src>>,
+        mut level_effects: impl Iterator<Item = anyhow::Result<Vec<Effect<'src>>>>,
+    ) -> anyhow::Result<Self> {
+        let Some(effects) = level_effects.next().transpose()? else {
+            bail!("Ability must have effect text for at least one level.")
+        };
+
+        let shape = effects_shape(&effects);
+        let mut values: Vec<LevelValue> = effects_numbers(&effects)
+            .into_iter()
+            .map(|(field, value)| LevelValue {
+                field,
+                levels: vec![value],
+            })
+            .collect();
+
+        for (i, other_effects) in level_effects.enumerate() {
+            let other_effects = other_effects?;
+            let diffs = diff_shapes(&shape, &effects_shape(&other_effects));
+            if !diffs.is_empty() {
+                bail!(
+                    "Level {} differs in structure from level 1:\n{}",
+                    i + 2,
+                    diffs.join("\n")
+                )
+            }
+            for (value, (_, other_value)) in values.iter_mut().zip(effects_numbers(&other_effects))
+            {
+                value.levels.push(other_value)
+            }
+        }
+
+        Ok(Ability {
+            trigger,
+            effects,
+            values,
+        })
+    }
+
+    /// Check that each effect can occur in the same phase as its trigger.
+    /// * See [`Effect::check_phase`].
+    pub fn phase_warnings(&self) -> Vec<PhaseWarning> {
+        self.effects
+            .iter()
+            .filter_map(|effect| effect.check_phase())
+            .collect()
+    }
+
+    /// Number of levels of ability.
+    pub fn levels(&self) -> usize {
+        self.values.first().map_or(1, |value| value.levels.len())
+    }
+
+    /// Get effects at some level.
+    ///
+    /// ### Params
+    /// * `level`
+    ///     * Level starting from 1.
+    ///
+    /// ### Returns
+    /// * [`Effect`]s at level or [`None`] if no such level.
+    pub fn at_level(&self, level: usize) -> Option<Vec<Effect<'src>>> {
+        if level == 0 || level > self.levels() {
+            return None;
+        }
+        let mut effects = self.effects.clone();
+        let mut values = self.values.iter();
+        effects.map_numbers("effects", &mut |_, value| {
+            values
+                .next()
+                .and_then(|level_value| level_value.levels.get(level - 1).copied())
+                .unwrap_or(value)
+        });
+        Some(effects)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        token::{actions::ActionType, attribute::EntityType, logic::LogicType, percent::Percent},
+        SAPText,
+    };
+
+    use super::{Ability, Effect, LevelValue};
+    use crate::value::Value;
+
+    #[test]
+    fn test_ability_level_values() {
+        let ability =
+            Ability::new("Sell", &["Gain 1 gold.", "Gain 2 gold.", "Gain 3 gold."]).unwrap();
+
+        assert_eq!(ability.levels(), 3);
+        assert_eq!(
+            ability.values,
+            [LevelValue {
+                field: "effects[0].entities[0]".to_owned(),
+                levels: vec![1, 2, 3]
+            }]
+        );
+        assert_eq!(ability.effects[0].action, Some(ActionType::Gain));
+        assert_eq!(ability.effects[0].entities, [EntityType::Gold(Some(1))]);
+
+        let trigger_tokens = SAPText::new("Sell").tokenize().unwrap();
+        let effect_tokens = SAPText::new("Gain 3 gold.").tokenize().unwrap();
+        let exp_effects = Effect::new(
+            trigger_tokens
+                .try_into()
+                .ok()
+                .and_then(|mut triggers: Vec<_>| triggers.pop()),
+            &effect_tokens,
+        )
+        .unwrap();
+        assert_eq!(ability.at_level(3).unwrap(), exp_effects);
+        assert_eq!(ability.at_level(4), None);
+    }
+
+    #[test]
+    fn test_ability_level_fractional_percent() {
+        let tokens = SAPText::new("Deal 50% attack damage to one random enemy.")
+            .tokenize()
+            .unwrap();
+        let effects = Effect::new(None, &tokens).unwrap();
+        let level_effects = [1250, 2500, 3750].map(|basis_points| {
+            let mut effects = effects.clone();
+            effects[0].entities = vec![EntityType::AttackPercent(Some(
+                Percent::from_basis_points(basis_points),
+            ))];
+            effects[0].amounts.clear();
+            effects[0].update_amounts();
+            effects
+        });
+        let ability =
+            Ability::from_level_effects(None, level_effects.clone().into_iter().map(Ok)).unwrap();
+
+        assert_eq!(
+            ability.values,
+            [
+                LevelValue {
+                    field: "effects[0].target_count".to_owned(),
+                    levels: vec![1, 1, 1]
+                },
+                LevelValue {
+                    field: "effects[0].entities[0]".to_owned(),
+                    levels: vec![1250, 2500, 3750]
+                }
+            ]
+        );
+        assert_eq!(ability.at_level(3).unwrap(), level_effects[2]);
+    }
+
+    #[test]
+    fn test_ability_level_item_counts() {
+        // Sheep
+        let ability = Ability::new(
+            "Faint",
+            &[
+                "Summon one 2/2 Ram.",
+                "Summon two 4/4 Rams.",
+                "Summon two 6/6 Rams.",
+            ],
+        )
+        .unwrap();
+        let summon = ability.at_level(2).unwrap().remove(0).summon.unwrap();
+        assert_eq!(summon.count, 2);
+        assert_eq!(summon.attack, Some(Value::Literal(4)));
+
+        // Canned Food
+        let ability = Ability::new(
+            "Buy",
+            &[
+                "Stock a free Canned Food.",
+                "Stock two free Canned Foods.",
+                "Stock three free Canned Foods.",
+            ],
+        )
+        .unwrap();
+        assert!(ability.values.contains(&LevelValue {
+            field: "effects[0].shop.count".to_owned(),
+            levels: vec![1, 2, 3]
+        }));
+
+        // Ant
+        let ability = Ability::new(
+            "Faint",
+            &[
+                "Give one random friend +2 attack and +1 health.",
+                "Give two random friends +2 attack and +1 health.",
+                "Give three random friends +2 attack and +1 health.",
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            ability.values[0],
+            LevelValue {
+                field: "effects[0].target_count".to_owned(),
+                levels: vec![1, 2, 3]
+            }
+        );
+        assert_eq!(ability.at_level(2).unwrap()[0].target_count, Some(2));
+    }
+
+    #[test]
+    fn test_ability_level_diff() {
+        let err = Ability::new(
+            "Sell",
+            &["Gain 1 gold.", "Gain 2 gold.", "Gain 3 trumpets."],
+        )
+        .unwrap_err();
+
+        let msg = err.to_string();
+        assert!(msg.starts_with("Level 3 differs in structure from level 1:"));
+        let fields: Vec<&str> = msg
+            .lines()
+            .skip(1)
+            .filter_map(|line| line.trim().split_once(':'))
+            .map(|(field, _)| field)
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                "effects[0].entities",
+                "effects[0].amounts",
+                "effects[0].position"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ability_parse_line() {
+        let arrow = Ability::parse("Faint → Summon one 1/1 Zombie Cricket.").unwrap();
+        let ascii_arrow = Ability::parse("Faint -> Summon one 1/1 Zombie Cricket.").unwrap();
+        let colon = Ability::parse("Faint: Summon one 1/1 Zombie Cricket.").unwrap();
+
+        assert_eq!(arrow, ascii_arrow);
+        assert_eq!(arrow, colon);
+        assert_eq!(arrow.len(), 1);
+        assert_eq!(arrow[0].levels(), 1);
+        assert_eq!(
+            arrow[0].trigger.as_ref().and_then(|trigger| trigger.action),
+            Some(ActionType::Faint)
+        );
+        assert_eq!(arrow[0].effects[0].action, Some(ActionType::Summon));
+    }
+
+    #[test]
+    fn test_ability_parse_line_multiple_triggers() {
+        let abilities = Ability::parse("End turn & Start of battle: Gain +1 attack.").unwrap();
+        let triggers: Vec<_> = abilities
+            .iter()
+            .map(|ability| ability.trigger.as_ref().and_then(|trigger| trigger.logic))
+            .collect();
+
+        assert_eq!(triggers, [Some(LogicType::End), Some(LogicType::Start)]);
+        assert!(abilities
+            .iter()
+            .all(|ability| ability.effects[0].trigger == ability.trigger));
+        assert!(Ability::parse("Gain +1 attack.").is_err());
+    }
+}
@@ -0,0 +1,52 @@
+//! Parse a JSON database of pets, foods, and toys.
+//!
+//! ```bash
+//! cargo run --features serde --bin saplex-ingest -- items.json parsed.json
+//! ```
+//!
+//! Add `--coverage` to also print a [`CoverageReport`] of all item text.
+
+use std::{fs, process::ExitCode};
+
+use saplex::{
+    coverage::CoverageReport,
+    ingest::{ingest, load_entries},
+};
+
+/// Usage text.
+const USAGE: &str = "Usage: saplex-ingest <input.json> [output.json] [--coverage]";
+
+fn main() -> anyhow::Result<ExitCode> {
+    let (flags, mut args): (Vec<String>, Vec<String>) = std::env::args()
+        .skip(1)
+        .partition(|arg| arg.starts_with("--"));
+    let show_coverage = flags.iter().any(|flag| flag == "--coverage");
+    let mut args = args.drain(..);
+    let Some(input) = args.next() else {
+        eprintln!("{USAGE}");
+        return Ok(ExitCode::FAILURE);
+    };
+    let output = args.next();
+
+    let entries = load_entries(&fs::read_to_string(&input)?)?;
+    let records = ingest(&entries);
+    let json = serde_json::to_string_pretty(&records)?;
+    match output {
+        Some(output) => fs::write(output, json)?,
+        None => println!("{json}"),
+    }
+
+    let n_ok = records.iter().filter(|record| record.is_ok()).count();
+    eprintln!("Parsed {n_ok}/{} entries from {input}.", records.len());
+
+    if show_coverage {
+        let report = CoverageReport::new(entries.iter().flat_map(|entry| {
+            entry
+                .levels
+                .iter()
+                .map(|level| (entry.trigger.as_str(), level.as_str()))
+        }));
+        eprintln!("{report}");
+    }
+    Ok(ExitCode::SUCCESS)
+}
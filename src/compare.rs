@@ -0,0 +1,201 @@
+use std::fmt::Display;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::token::{
+    attribute::EntityType, logic::LogicType, numeric::NumericType, position::PositionType,
+    target::TargetType, types::TokenType, Token,
+};
+
+/// Comparison operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CompareOp {
+    /// Less than.
+    /// - ex. `lower tier`
+    Less,
+    /// Less than or equal.
+    /// - ex. `tier 3 or lower`
+    LessEqual,
+    /// Equal.
+    /// - ex. `equal attack`
+    Equal,
+    /// Greater than or equal.
+    /// - ex. `tier 3 or higher`
+    GreaterEqual,
+    /// Greater than.
+    /// - ex. `higher attack`
+    Greater,
+}
+
+impl CompareOp {
+    /// Convert a [`NumericType`] comparison token to a [`CompareOp`].
+    ///
+    /// ### Params
+    /// * `num_type`
+    ///     * [`NumericType::LessEqual`], [`NumericType::Equal`], or [`NumericType::GreaterEqual`].
+    /// * `inclusive`
+    ///     * If comparison includes equality.
+    ///     * ex. `or lower`
+    ///
+    /// ### Returns
+    /// * [`CompareOp`] or [`None`] if not a comparison token.
+    pub fn from_numeric(num_type: NumericType, inclusive: bool) -> Option<Self> {
+        Some(match (num_type, inclusive) {
+            (NumericType::LessEqual, true) => CompareOp::LessEqual,
+            (NumericType::LessEqual, false) => CompareOp::Less,
+            (NumericType::Equal, _) => CompareOp::Equal,
+            (NumericType::GreaterEqual, true) => CompareOp::GreaterEqual,
+            (NumericType::GreaterEqual, false) => CompareOp::Greater,
+            _ => return None,
+        })
+    }
+}
+
+impl Display for CompareOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            CompareOp::Less => "<",
+            CompareOp::LessEqual => "<=",
+            CompareOp::Equal => "=",
+            CompareOp::GreaterEqual => ">=",
+            CompareOp::Greater => ">",
+        })
+    }
+}
+
+/// Operand of a [`Comparison`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Operand {
+    /// Item affected by the effect.
+    /// - ex. `Summon a lower tier pet`
+    Affected,
+    /// Pet at a position.
+    /// - ex. `this`, `it`
+    Position(PositionType),
+    /// Pets of a target.
+    /// - ex. `the enemy`
+    Target(TargetType),
+    /// A number.
+    /// - ex. `tier 3 or lower`
+    Number(i32),
+}
+
+/// A comparison of a stat between two operands.
+/// - ex. `the enemy has higher attack than this`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Comparison<'src> {
+    /// Left-hand operand.
+    pub lhs: Operand,
+    /// Comparison operator.
+    pub op: CompareOp,
+    /// Stat compared.
+    /// * Inner value is always [`None`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub stat: EntityType<'src>,
+    /// Right-hand operand.
+    pub rhs: Operand,
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operand::Affected => f.write_str("affected"),
+            Operand::Position(pos) => write!(f, "{pos}"),
+            Operand::Target(target) => write!(f, "{target}"),
+            Operand::Number(num) => write!(f, "{num}"),
+        }
+    }
+}
+
+impl<'src> Display for Comparison<'src> {
+    /// Format comparison with the stat of the left-hand operand.
+    /// - ex. `affected tier < this`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {} {}", self.lhs, self.stat, self.op, self.rhs)
+    }
+}
+
+/// Get stat [`EntityType`] without its value and the value, if any.
+pub(crate) fn split_stat<'src>(
+    entity: &EntityType<'src>,
+) -> Option<(EntityType<'src>, Option<i32>)> {
+    Some(match entity {
+        EntityType::Attack(v) => (EntityType::Attack(None), *v),
+        EntityType::Health(v) => (EntityType::Health(None), *v),
+        EntityType::Damage(v) => (EntityType::Damage(None), *v),
+        EntityType::Gold(v) => (EntityType::Gold(None), *v),
+        EntityType::Trumpet(v) => (EntityType::Trumpet(None), *v),
+        EntityType::Tier(v) => (EntityType::Tier(None), *v),
+        EntityType::Level(v) => (EntityType::Level(None), *v),
+        EntityType::Experience(v) => (EntityType::Experience(None), *v),
+        _ => return None,
+    })
+}
+
+impl Operand {
+    /// Convert [`TokenType`] to an [`Operand`], if possible.
+    fn from_token_type(ttype: &TokenType) -> Option<Self> {
+        match ttype {
+            TokenType::Position(pos) => Some(Operand::Position(*pos)),
+            TokenType::Target(target) => Some(Operand::Target(*target)),
+            TokenType::Numeric(NumericType::Number(Some(num))) => Some(Operand::Number(*num)),
+            _ => None,
+        }
+    }
+}
+
+impl<'src> Comparison<'src> {
+    /// Build a [`Comparison`] from the tokens of a predicate.
+    /// * ex. `the enemy has higher attack than this`
+    /// * ex. `it is tier 3 or lower`
+    ///
+    /// ### Params
+    /// * `tokens`
+    ///     * Predicate tokens.
+    /// * `default_lhs`
+    ///     * [`Operand`] used if no left-hand operand is found.
+    ///
+    /// ### Returns
+    /// * [`Comparison`] or [`None`] if no comparison token or stat.
+    pub(crate) fn from_tokens(tokens: &[&Token<'src>], default_lhs: Operand) -> Option<Self> {
+        let (op_idx, op_token) = tokens.iter().enumerate().find(|(_, token)| {
+            matches!(
+                token.ttype,
+                TokenType::Numeric(
+                    NumericType::LessEqual | NumericType::Equal | NumericType::GreaterEqual
+                )
+            )
+        })?;
+        let TokenType::Numeric(num_type) = op_token.ttype else {
+            return None;
+        };
+        let inclusive = op_idx
+            .checked_sub(1)
+            .and_then(|idx| tokens.get(idx))
+            .is_some_and(|token| token.ttype == TokenType::Logic(LogicType::Or));
+        let op = CompareOp::from_numeric(num_type, inclusive)?;
+
+        let (before, after) = tokens.split_at(op_idx);
+        let (stat, stat_value) = tokens.iter().find_map(|token| match &token.ttype {
+            TokenType::Entity(entity) => split_stat(entity),
+            _ => None,
+        })?;
+
+        let lhs = before
+            .iter()
+            .rev()
+            .find_map(|token| Operand::from_token_type(&token.ttype))
+            .unwrap_or(default_lhs);
+        let rhs = after
+            .iter()
+            .find_map(|token| Operand::from_token_type(&token.ttype))
+            .or(stat_value.map(Operand::Number))
+            .unwrap_or(Operand::Position(PositionType::OnSelf));
+
+        Some(Comparison { lhs, op, stat, rhs })
+    }
+}
@@ -0,0 +1,120 @@
+use std::fmt::Display;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{compare::Comparison, token::logic::LogicType, trigger::EffectTrigger};
+
+/// A condition for an effect to occur.
+/// - ex. `If this has a level 3 friend and is in battle, ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Condition<'src> {
+    /// Atomic predicate.
+    /// - ex. `this has a level 3 friend`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    Predicate(EffectTrigger<'src>),
+    /// Comparison predicate.
+    /// - ex. `the enemy has higher attack than this`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    Compare(Comparison<'src>),
+    /// All conditions must be met.
+    And(Vec<Condition<'src>>),
+    /// Any condition must be met.
+    Or(Vec<Condition<'src>>),
+    /// Condition must not be met.
+    Not(Box<Condition<'src>>),
+}
+
+impl<'src> Display for Condition<'src> {
+    /// Format condition with nested conjunctions in parentheses.
+    /// - ex. `has friend level 3 and (battle or not shop)`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let join = |conds: &[Condition], sep: &str| {
+            conds
+                .iter()
+                .map(|cond| match cond {
+                    Condition::And(_) | Condition::Or(_) => format!("({cond})"),
+                    cond => cond.to_string(),
+                })
+                .collect::<Vec<String>>()
+                .join(sep)
+        };
+        match self {
+            Condition::Predicate(pred) => write!(f, "{pred}"),
+            Condition::Compare(cmp) => write!(f, "{cmp}"),
+            Condition::And(conds) => f.write_str(&join(conds, " and ")),
+            Condition::Or(conds) => f.write_str(&join(conds, " or ")),
+            Condition::Not(cond) => write!(f, "not {cond}"),
+        }
+    }
+}
+
+impl<'src> From<EffectTrigger<'src>> for Condition<'src> {
+    fn from(trigger: EffectTrigger<'src>) -> Self {
+        Condition::Predicate(trigger)
+    }
+}
+
+impl<'src> Condition<'src> {
+    /// Build a condition from predicates joined by [`LogicType::And`] or [`LogicType::Or`].
+    /// * [`LogicType::And`] takes precedence over [`LogicType::Or`].
+    ///     * ex. `a and b or c` -> `(a and b) or c`
+    ///
+    /// ### Params
+    /// * `first`
+    ///     * First predicate.
+    /// * `rest`
+    ///     * Remaining predicates and the logic joining each to the previous predicate.
+    ///
+    /// ### Returns
+    /// * [`Condition`]
+    pub fn from_predicates(
+        first: Condition<'src>,
+        rest: impl IntoIterator<Item = (LogicType, Condition<'src>)>,
+    ) -> Self {
+        let mut any_conds: Vec<Vec<Condition>> = vec![vec![first]];
+        for (logic, pred) in rest {
+            match (logic, any_conds.last_mut()) {
+                (LogicType::And, Some(all_conds)) => all_conds.push(pred),
+                _ => any_conds.push(vec![pred]),
+            }
+        }
+        let mut any_conds: Vec<Condition> = any_conds
+            .into_iter()
+            .map(|mut all_conds| {
+                if all_conds.len() == 1 {
+                    all_conds.remove(0)
+                } else {
+                    Condition::And(all_conds)
+                }
+            })
+            .collect();
+
+        if any_conds.len() == 1 {
+            any_conds.remove(0)
+        } else {
+            Condition::Or(any_conds)
+        }
+    }
+
+    /// Negate condition.
+    pub fn negate(self) -> Self {
+        match self {
+            Condition::Not(cond) => *cond,
+            cond => Condition::Not(Box::new(cond)),
+        }
+    }
+
+    /// All atomic predicates in condition.
+    pub fn predicates(&self) -> Vec<&EffectTrigger<'src>> {
+        match self {
+            Condition::Predicate(pred) => vec![pred],
+            Condition::Compare(_) => vec![],
+            Condition::And(conds) | Condition::Or(conds) => {
+                conds.iter().flat_map(|cond| cond.predicates()).collect()
+            }
+            Condition::Not(cond) => cond.predicates(),
+        }
+    }
+}
@@ -0,0 +1,286 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Display,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ability::Ability,
+    token::{
+        actions::ActionType, attribute::EntityType, logic::LogicType, numeric::NumericType,
+        position::PositionType, target::TargetType, types::TokenType, SAPTokens,
+    },
+    SAPText,
+};
+
+/// Coverage of a corpus of SAP text by the tokenizer and parser.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CoverageReport {
+    /// Number of texts.
+    pub total: usize,
+    /// Number of texts parsed without error.
+    pub parsed: usize,
+    /// Words ignored by the tokenizer and their frequency.
+    /// * Most frequent first.
+    pub unknown_words: Vec<(String, usize)>,
+    /// Words of each token type never used in corpus.
+    pub unused_vocab: BTreeMap<String, Vec<String>>,
+}
+
+/// Name and vocabulary of each token type.
+const TOKEN_VOCABS: [(&str, &[&str]); 6] = [
+    ("NumericType", NumericType::VOCAB),
+    ("EntityType", EntityType::VOCAB),
+    ("PositionType", PositionType::VOCAB),
+    ("TargetType", TargetType::VOCAB),
+    ("LogicType", LogicType::VOCAB),
+    ("ActionType", ActionType::VOCAB),
+];
+
+/// Get name of token type.
+/// * See [`TOKEN_VOCABS`].
+fn token_type_name(ttype: &TokenType) -> Option<&'static str> {
+    Some(match ttype {
+        TokenType::Numeric(_) => "NumericType",
+        TokenType::Entity(_) => "EntityType",
+        TokenType::Position(_) => "PositionType",
+        TokenType::Target(_) => "TargetType",
+        TokenType::Logic(_) => "LogicType",
+        TokenType::Action(_) => "ActionType",
+        TokenType::EndText => return None,
+    })
+}
+
+/// Get byte ranges of words in text.
+/// * Words are alphabetic characters including inner hyphens.
+///     * ex. `left-most`
+fn word_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = vec![];
+    let mut start: Option<usize> = None;
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    for (i, (idx, chr)) in chars.iter().enumerate() {
+        let is_inner_hyphen = *chr == '-'
+            && start.is_some()
+            && chars
+                .get(i + 1)
+                .is_some_and(|(_, next_chr)| next_chr.is_ascii_alphabetic());
+        match (chr.is_ascii_alphabetic() || is_inner_hyphen, start) {
+            (true, None) => start = Some(*idx),
+            (false, Some(word_start)) => {
+                spans.push((word_start, *idx));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(word_start) = start {
+        spans.push((word_start, text.len()))
+    }
+    spans
+}
+
+/// Builder of a [`CoverageReport`].
+#[derive(Debug, Default)]
+struct CoverageCounter {
+    /// Report being built.
+    report: CoverageReport,
+    /// Frequency of ignored words.
+    unknown_words: HashMap<String, usize>,
+    /// Token type name and used word.
+    used_vocab: HashSet<(&'static str, String)>,
+}
+
+impl CoverageCounter {
+    /// Count words in text covered and not covered by tokens.
+    fn add_tokens(&mut self, text: &str, tokens: &SAPTokens) {
+        for (start, end) in word_spans(text) {
+            let is_covered = tokens
+                .iter()
+                .any(|token| token.metadata.start < end && start < token.metadata.current);
+            if !is_covered {
+                *self
+                    .unknown_words
+                    .entry(text[start..end].to_ascii_lowercase())
+                    .or_default() += 1;
+            }
+        }
+        for token in tokens.iter() {
+            let Some(name) = token_type_name(&token.ttype) else {
+                continue;
+            };
+            let token_text = token.text.to_ascii_lowercase();
+            for (start, end) in word_spans(&token_text) {
+                self.used_vocab
+                    .insert((name, token_text[start..end].to_owned()));
+            }
+            self.used_vocab.insert((name, token_text));
+        }
+    }
+
+    /// Add trigger and effect text to coverage.
+    fn add(&mut self, trigger: &str, effect: &str) {
+        self.report.total += 1;
+        for text in [trigger, effect] {
+            if let Ok(tokens) = SAPText::new(text).tokenize() {
+                self.add_tokens(text, &tokens);
+            }
+        }
+        if Ability::from_triggers(trigger, &[effect]).is_ok() {
+            self.report.parsed += 1;
+        }
+    }
+
+    /// Finish building [`CoverageReport`].
+    fn finish(mut self) -> CoverageReport {
+        let mut unknown_words: Vec<(String, usize)> = self.unknown_words.into_iter().collect();
+        unknown_words.sort_by(|(word, count), (other_word, other_count)| {
+            other_count.cmp(count).then(word.cmp(other_word))
+        });
+        self.report.unknown_words = unknown_words;
+
+        for (name, vocab) in TOKEN_VOCABS {
+            let unused: Vec<String> = vocab
+                .iter()
+                .filter(|word| !self.used_vocab.contains(&(name, word.to_string())))
+                .map(|word| word.to_string())
+                .collect();
+            self.report.unused_vocab.insert(name.to_owned(), unused);
+        }
+        self.report
+    }
+}
+
+impl CoverageReport {
+    /// Measure coverage of a corpus of trigger and effect text.
+    ///
+    /// ### Params
+    /// * `corpus`
+    ///     * Trigger and effect text.
+    ///     * Trigger text is empty if no trigger.
+    ///
+    /// ### Returns
+    /// * [`CoverageReport`]
+    ///
+    /// ```
+    /// use saplex::coverage::CoverageReport;
+    ///
+    /// let report = CoverageReport::new([
+    ///     ("Sell", "Gain 1 gold."),
+    ///     ("Faint", "Gain 1 gold and a shiny pebble."),
+    /// ]);
+    /// assert_eq!(report.parsed, 2);
+    /// assert!(report.unknown_words.contains(&("pebble".to_owned(), 1)));
+    /// println!("{report}");
+    /// ```
+    pub fn new<'a>(corpus: impl IntoIterator<Item = (&'a str, &'a str)>) -> Self {
+        let mut counter = CoverageCounter::default();
+        for (trigger, effect) in corpus {
+            counter.add(trigger, effect);
+        }
+        counter.finish()
+    }
+
+    /// Fraction of texts parsed without error.
+    pub fn success_rate(&self) -> f32 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        self.parsed as f32 / self.total as f32
+    }
+
+    /// Serialize report to JSON.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+impl Display for CoverageReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Parsed {}/{} texts ({:.1}%).",
+            self.parsed,
+            self.total,
+            self.success_rate() * 100.0
+        )?;
+        writeln!(f, "\nUnknown words:")?;
+        for (word, count) in self.unknown_words.iter() {
+            writeln!(f, "  {word}: {count}")?;
+        }
+        writeln!(f, "\nUnused vocabulary:")?;
+        for (name, words) in self.unused_vocab.iter() {
+            writeln!(f, "  {name}: {}", words.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use crate::token::{
+        actions::ActionType, attribute::EntityType, logic::LogicType, numeric::NumericType,
+        position::PositionType, target::TargetType,
+    };
+
+    use super::CoverageReport;
+
+    #[test]
+    fn test_coverage_report() {
+        let report = CoverageReport::new([
+            ("Sell", "Gain 1 gold."),
+            ("End turn", "If the friend behind is in battle, +1 health."),
+            ("", "Give the left-most friend +1 attack using magic."),
+        ]);
+
+        assert_eq!((report.parsed, report.total), (2, 3));
+        assert_eq!(
+            report.unknown_words,
+            [
+                ("the".to_owned(), 2),
+                ("magic".to_owned(), 1),
+                ("using".to_owned(), 1)
+            ]
+        );
+        let unused_actions = &report.unused_vocab["ActionType"];
+        assert!(!unused_actions.contains(&"gain".to_owned()));
+        assert!(!unused_actions.contains(&"sell".to_owned()));
+        assert!(unused_actions.contains(&"summon".to_owned()));
+        let unused_positions = &report.unused_vocab["PositionType"];
+        assert!(!unused_positions.contains(&"left-most".to_owned()));
+
+        #[cfg(feature = "serde")]
+        assert!(report.to_json().unwrap().contains("\"unknown_words\""));
+
+        let text = report.to_string();
+        assert!(text.starts_with("Parsed 2/3 texts (66.7%)."));
+        assert!(text.contains("  magic: 1"));
+    }
+
+    #[test]
+    fn test_vocab_parses() {
+        for word in ActionType::VOCAB {
+            assert!(ActionType::from_str(word).is_ok(), "{word}");
+        }
+        for word in EntityType::VOCAB {
+            assert!(EntityType::from_str(word).is_ok(), "{word}");
+        }
+        for word in LogicType::VOCAB {
+            assert!(LogicType::from_str(word).is_ok(), "{word}");
+        }
+        for word in NumericType::VOCAB {
+            assert!(NumericType::from_str(word).is_ok(), "{word}");
+        }
+        for word in PositionType::VOCAB {
+            assert!(PositionType::from_str(word).is_ok(), "{word}");
+        }
+        for word in TargetType::VOCAB {
+            assert!(TargetType::from_str(word).is_ok(), "{word}");
+        }
+    }
+}
@@ -0,0 +1,637 @@
+use std::fmt::Display;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ability::Ability,
+    duration::Duration,
+    effect::Effect,
+    shop::{PriceChange, ShopEffect},
+    token::{attribute::EntityType, position::PositionType, target::TargetType},
+    trigger::EffectTrigger,
+};
+
+/// Change to a field between two versions of an effect.
+/// - ex. `Attack 2→3 at level 2`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FieldChange {
+    /// Name of changed field.
+    /// - ex. `target`, `trigger.action`, `Attack`
+    pub field: String,
+    /// Old value or [`None`] if field was added.
+    pub old: Option<String>,
+    /// New value or [`None`] if field was removed.
+    pub new: Option<String>,
+    /// Level of ability changed or [`None`] if changed at all levels.
+    pub level: Option<usize>,
+    /// Whether old and new values are numbers.
+    /// - ex. `Attack 2→3`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub numeric: bool,
+}
+
+impl FieldChange {
+    /// Create a change between two values of a field.
+    /// * Values are [`None`] if the field is absent.
+    fn new(field: impl Into<String>, old: Option<String>, new: Option<String>) -> Self {
+        FieldChange {
+            field: field.into(),
+            old,
+            new,
+            level: None,
+            numeric: false,
+        }
+    }
+
+    /// Create a change between two numeric values of a field.
+    fn numeric(field: impl Into<String>, old: Option<String>, new: Option<String>) -> Self {
+        FieldChange {
+            numeric: true,
+            ..FieldChange::new(field, old, new)
+        }
+    }
+
+    /// Prefix field name with a path.
+    fn prefixed(mut self, prefix: &str) -> Self {
+        if !prefix.is_empty() {
+            self.field = format!("{prefix}.{}", self.field)
+        }
+        self
+    }
+}
+
+impl Display for FieldChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let field = &self.field;
+        match (&self.old, &self.new) {
+            (Some(old), Some(new)) if self.numeric => write!(f, "{field} {old}→{new}")?,
+            (Some(old), Some(new)) => write!(f, "{field} changed from {old} to {new}")?,
+            (None, Some(new)) => write!(f, "Added {field} {new}")?,
+            (Some(old), None) => write!(f, "Removed {field} {old}")?,
+            (None, None) => write!(f, "{field} unchanged")?,
+        }
+        if let Some(level) = self.level {
+            write!(f, " at level {level}")?
+        }
+        Ok(())
+    }
+}
+
+/// Render changes as patch notes with one change per line.
+///
+/// ```
+/// use saplex::{ability::Ability, diff::patch_notes};
+///
+/// let old = Ability::new("Faint", &["Give one random friend +2 attack."]).unwrap();
+/// let new = Ability::new("Faint", &["Give the friend behind +3 attack."]).unwrap();
+/// assert_eq!(
+///     patch_notes(&old.diff(&new)),
+///     "- target changed from random friend to friend behind\n- Removed target_count 1\n- Attack 2→3\n"
+/// );
+/// ```
+pub fn patch_notes(changes: &[FieldChange]) -> String {
+    changes
+        .iter()
+        .map(|change| format!("- {change}\n"))
+        .collect()
+}
+
+/// Text of an optional value or [`None`] if no value.
+fn option_text<T: Display>(value: &Option<T>) -> Option<String> {
+    value.as_ref().map(ToString::to_string)
+}
+
+/// Text of a list of values or [`None`] if no values.
+/// - ex. `2 attack, 1 health`
+fn list_text<T: Display>(values: &[T]) -> Option<String> {
+    (!values.is_empty()).then(|| {
+        values
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>()
+            .join(", ")
+    })
+}
+
+/// Push change to `changes` if two values of a field differ.
+fn push_change(
+    changes: &mut Vec<FieldChange>,
+    field: &str,
+    old: Option<String>,
+    new: Option<String>,
+) {
+    if old != new {
+        changes.push(FieldChange::new(field, old, new))
+    }
+}
+
+/// Push numeric change to `changes` if two numbers of a field differ.
+fn push_numeric_change<T: Display + PartialEq>(
+    changes: &mut Vec<FieldChange>,
+    field: &str,
+    old: Option<T>,
+    new: Option<T>,
+) {
+    if old != new {
+        changes.push(FieldChange::numeric(
+            field,
+            option_text(&old),
+            option_text(&new),
+        ))
+    }
+}
+
+/// Macro to collect changes to optional fields that differ between two items.
+macro_rules! diff_option_fields {
+    ($changes:ident, $item:expr, $other:expr, [$($field:ident),+]) => {
+        $(
+            push_change(
+                &mut $changes,
+                stringify!($field),
+                option_text(&$item.$field),
+                option_text(&$other.$field),
+            );
+        )+
+    };
+}
+
+/// Text of the target and positions of an effect.
+/// - ex. `random friend`, `friend behind`
+fn target_text(target: Option<TargetType>, positions: &[PositionType]) -> Option<String> {
+    let is_after = |pos: &&PositionType| matches!(pos, PositionType::Ahead | PositionType::Behind);
+    let words: Vec<String> = positions
+        .iter()
+        .filter(|pos| !is_after(pos))
+        .map(ToString::to_string)
+        .chain(target.map(|target| target.to_string()))
+        .chain(positions.iter().filter(is_after).map(ToString::to_string))
+        .collect();
+    (!words.is_empty()).then(|| words.join(" "))
+}
+
+/// Text summarizing an effect by its action, target, and entities.
+/// - ex. `give 1 random friend 1 health`
+fn effect_text(effect: &Effect) -> String {
+    let entities: Vec<String> = effect.entities.iter().map(ToString::to_string).collect();
+    [
+        option_text(&effect.action),
+        option_text(&effect.target_count),
+        target_text(effect.target, &effect.position),
+        (!entities.is_empty()).then(|| entities.join(" and ")),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<String>>()
+    .join(" ")
+}
+
+/// Collect changes between two lists of entities.
+/// * Entities are compared as a set so reordering them is not a change.
+/// * Changed entities of the same kind only report their change in value.
+fn diff_entities(changes: &mut Vec<FieldChange>, entities: &[EntityType], other: &[EntityType]) {
+    let mut removed: Vec<&EntityType> = entities.iter().collect();
+    let mut added: Vec<&EntityType> = vec![];
+    for entity in other {
+        match removed.iter().position(|old_entity| *old_entity == entity) {
+            Some(idx) => {
+                removed.remove(idx);
+            }
+            None => added.push(entity),
+        }
+    }
+    added.retain(|entity| {
+        let Some(idx) = removed
+            .iter()
+            .position(|old_entity| old_entity.without_value() == entity.without_value())
+        else {
+            return true;
+        };
+        let old_entity = removed.remove(idx);
+        changes.push(FieldChange::numeric(
+            entity.kind_name(),
+            old_entity.value_text(),
+            entity.value_text(),
+        ));
+        false
+    });
+    for idx in 0..removed.len().max(added.len()) {
+        changes.push(FieldChange::new(
+            "entities",
+            removed.get(idx).map(ToString::to_string),
+            added.get(idx).map(ToString::to_string),
+        ))
+    }
+}
+
+impl<'src> EffectTrigger<'src> {
+    /// Get field-level changes from this trigger to another.
+    ///
+    /// ### Params
+    /// * `other`
+    ///     * New version of trigger.
+    ///
+    /// ### Returns
+    /// * [`FieldChange`] for each changed field.
+    pub fn diff(&self, other: &EffectTrigger) -> Vec<FieldChange> {
+        let mut changes = vec![];
+        diff_option_fields!(
+            changes,
+            self,
+            other,
+            [action, entity, target, logic, prim_pos, sec_pos]
+        );
+        push_numeric_change(&mut changes, "number", self.number, other.number);
+        push_change(
+            &mut changes,
+            "count",
+            Some(self.count.to_string()),
+            Some(other.count.to_string()),
+        );
+        changes
+    }
+}
+
+impl<'src> Effect<'src> {
+    /// Get field-level changes from this effect to another.
+    /// * Entities are compared as a set. Amounts, summons, shop effects, and ability references only report changes not already in entities.
+    ///
+    /// ### Params
+    /// * `other`
+    ///     * New version of effect.
+    ///
+    /// ### Returns
+    /// * [`FieldChange`] for each changed field.
+    pub fn diff(&self, other: &Effect<'src>) -> Vec<FieldChange> {
+        let mut changes = vec![];
+        match (&self.trigger, &other.trigger) {
+            (Some(trigger), Some(other_trigger)) => changes.extend(
+                trigger
+                    .diff(other_trigger)
+                    .into_iter()
+                    .map(|change| change.prefixed("trigger")),
+            ),
+            (trigger, other_trigger) => {
+                push_change(
+                    &mut changes,
+                    "trigger",
+                    option_text(trigger),
+                    option_text(other_trigger),
+                );
+            }
+        }
+        diff_option_fields!(changes, self, other, [cond_trigger, condition, action]);
+        push_change(
+            &mut changes,
+            "target",
+            target_text(self.target, &self.position),
+            target_text(other.target, &other.position),
+        );
+        push_numeric_change(
+            &mut changes,
+            "target_count",
+            self.target_count,
+            other.target_count,
+        );
+        diff_entities(&mut changes, &self.entities, &other.entities);
+        // Amounts from entities are already compared as entities.
+        let explicit_amounts = |effect: &Effect<'src>| {
+            let n_derived = effect.derived_amounts().len();
+            list_text(effect.amounts.get(n_derived..).unwrap_or_default())
+        };
+        push_change(
+            &mut changes,
+            "amounts",
+            explicit_amounts(self),
+            explicit_amounts(other),
+        );
+        // Other fields of summons, shop effects, and ability references are from entities, amounts, positions, or the action.
+        if let (Some(summon), Some(other_summon)) = (&self.summon, &other.summon) {
+            push_numeric_change(
+                &mut changes,
+                "summon.count",
+                Some(summon.count),
+                Some(other_summon.count),
+            );
+        }
+        if let (Some(shop), Some(other_shop)) = (&self.shop, &other.shop) {
+            push_numeric_change(&mut changes, "shop.count", shop.count, other_shop.count);
+            let free = |shop: &ShopEffect| {
+                option_text(
+                    &shop
+                        .price_change
+                        .filter(|price| *price == PriceChange::Free),
+                )
+            };
+            push_change(
+                &mut changes,
+                "shop.price_change",
+                free(shop),
+                free(other_shop),
+            );
+        }
+        if let (Some(ability_ref), Some(other_ability_ref)) =
+            (&self.ability_ref, &other.ability_ref)
+        {
+            push_numeric_change(
+                &mut changes,
+                "ability_ref.count",
+                Some(ability_ref.count),
+                Some(other_ability_ref.count),
+            );
+        }
+        push_change(
+            &mut changes,
+            "comparisons",
+            list_text(&self.comparisons),
+            list_text(&other.comparisons),
+        );
+        push_change(
+            &mut changes,
+            "excluded",
+            list_text(&self.excluded),
+            list_text(&other.excluded),
+        );
+        diff_option_fields!(changes, self, other, [usage]);
+        let duration = |effect: &Effect| {
+            option_text(
+                &Some(effect.duration).filter(|duration| *duration != Duration::Unspecified),
+            )
+        };
+        push_change(&mut changes, "duration", duration(self), duration(other));
+        changes
+    }
+}
+
+/// Get field-level changes from one list of effects to another.
+/// * Fields are prefixed with the index of their effect if either list has more than one effect.
+///     * ex. `effects[1].action`
+///
+/// ### Params
+/// * `effects`
+///     * Old effects.
+/// * `other`
+///     * New effects.
+///
+/// ### Returns
+/// * [`FieldChange`] for each changed field.
+pub fn diff_effects(effects: &[Effect], other: &[Effect]) -> Vec<FieldChange> {
+    let is_multi = effects.len() > 1 || other.len() > 1;
+    let mut changes = vec![];
+    for idx in 0..effects.len().max(other.len()) {
+        let prefix = if is_multi {
+            format!("effects[{idx}]")
+        } else {
+            String::new()
+        };
+        match (effects.get(idx), other.get(idx)) {
+            (Some(effect), Some(other_effect)) => changes.extend(
+                effect
+                    .diff(other_effect)
+                    .into_iter()
+                    .map(|change| change.prefixed(&prefix)),
+            ),
+            (effect, other_effect) => changes.push(FieldChange::new(
+                format!("effects[{idx}]"),
+                effect.map(effect_text),
+                other_effect.map(effect_text),
+            )),
+        }
+    }
+    changes
+}
+
+impl<'src> Ability<'src> {
+    /// Get field-level changes from this ability to another.
+    /// * Changes made identically at every level are reported once.
+    /// * Other changes are reported per level. See [`FieldChange::level`].
+    ///
+    /// ### Params
+    /// * `other`
+    ///     * New version of ability.
+    ///
+    /// ### Returns
+    /// * [`FieldChange`] for each changed field.
+    pub fn diff(&self, other: &Ability) -> Vec<FieldChange> {
+        let n_levels = self.levels().max(other.levels());
+        let level_changes: Vec<Vec<FieldChange>> = (1..=n_levels)
+            .map(|level| {
+                let effects = self.at_level(level).unwrap_or_default();
+                let other_effects = other.at_level(level).unwrap_or_default();
+                diff_effects(&effects, &other_effects)
+            })
+            .collect();
+
+        let mut changes: Vec<FieldChange> = vec![];
+        for (level, level_change) in level_changes
+            .iter()
+            .enumerate()
+            .flat_map(|(i, level_changes)| level_changes.iter().map(move |change| (i + 1, change)))
+        {
+            let is_all_levels = level_changes
+                .iter()
+                .all(|level_changes| level_changes.contains(level_change));
+            if !is_all_levels {
+                changes.push(FieldChange {
+                    level: Some(level),
+                    ..level_change.clone()
+                })
+            } else if !changes.contains(level_change) {
+                changes.push(level_change.clone())
+            }
+        }
+        changes
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ability::Ability, EffectTrigger, SAPText};
+
+    use super::{patch_notes, FieldChange};
+
+    #[test]
+    fn test_diff_trigger() {
+        let parse = |txt: &'static str| -> EffectTrigger<'static> {
+            let mut triggers: Vec<EffectTrigger> =
+                SAPText::new(txt).tokenize().unwrap().try_into().unwrap();
+            triggers.remove(0)
+        };
+        let changes = parse("Friend ahead faints").diff(&parse("Friend faints"));
+        assert_eq!(
+            changes,
+            [FieldChange {
+                field: "prim_pos".to_owned(),
+                old: Some("ahead".to_owned()),
+                new: None,
+                level: None,
+                numeric: false
+            }]
+        );
+        assert_eq!(changes[0].to_string(), "Removed prim_pos ahead");
+        assert!(parse("Faint").diff(&parse("Faint")).is_empty());
+    }
+
+    #[test]
+    fn test_diff_ability() {
+        let old = Ability::new(
+            "Faint",
+            &[
+                "Give one random friend +2 attack and +1 health.",
+                "Give one random friend +4 attack and +2 health.",
+                "Give one random friend +6 attack and +3 health.",
+            ],
+        )
+        .unwrap();
+        let new = Ability::new(
+            "Faint",
+            &[
+                "Give the friend behind +2 attack and +1 health.",
+                "Give the friend behind +3 attack and +2 health.",
+                "Give the friend behind +6 attack and +4 health.",
+            ],
+        )
+        .unwrap();
+
+        let changes = old.diff(&new);
+        assert_eq!(
+            changes.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            [
+                "target changed from random friend to friend behind",
+                "Removed target_count 1",
+                "Attack 4→3 at level 2",
+                "Health 3→4 at level 3",
+            ]
+        );
+        assert_eq!(
+            patch_notes(&changes),
+            "- target changed from random friend to friend behind\n\
+            - Removed target_count 1\n\
+            - Attack 4→3 at level 2\n\
+            - Health 3→4 at level 3\n"
+        );
+    }
+
+    #[test]
+    fn test_diff_numeric_change() {
+        let old = Ability::parse("Faint: Deal 50% attack damage to one random enemy.").unwrap();
+        let new = Ability::parse("Faint: Deal 75% attack damage to one random enemy.").unwrap();
+        let changes = old[0].diff(&new[0]);
+        assert!(changes[0].numeric);
+        assert_eq!(changes[0].to_string(), "AttackPercent 50%→75%");
+
+        let old = Ability::parse("Faint: Summon one 1/1 Zombie Cricket.").unwrap();
+        let new = Ability::parse("Faint: Summon one 2/2 Zombie Cricket.").unwrap();
+        let changes = old[0].diff(&new[0]);
+        assert!(changes.iter().all(|change| change.numeric));
+
+        // Changes between numbers of different kinds are not numeric.
+        let old = Ability::parse("Sell: Gain 1 gold.").unwrap();
+        let new = Ability::parse("Sell: Gain 1 trumpet.").unwrap();
+        let changes = old[0].diff(&new[0]);
+        assert!(changes.iter().all(|change| !change.numeric));
+        assert!(changes
+            .iter()
+            .any(|change| change.to_string() == "entities changed from 1 gold to 1 trumpet"));
+    }
+
+    #[test]
+    fn test_diff_effect_count() {
+        let old = Ability::parse("Faint: Gain +1 attack.").unwrap();
+        let new =
+            Ability::parse("Faint: Gain +1 attack and give one random friend +1 health.").unwrap();
+        let changes = old[0].diff(&new[0]);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].field, "effects[1]");
+        assert!(changes[0].old.is_none() && changes[0].new.is_some());
+    }
+
+    #[test]
+    fn test_diff_counts_with_entity_changes() {
+        let diff = |old: &str, new: &str| -> Vec<String> {
+            let old = Ability::parse(old).unwrap();
+            let new = Ability::parse(new).unwrap();
+            old[0]
+                .diff(&new[0])
+                .iter()
+                .map(ToString::to_string)
+                .collect()
+        };
+        assert_eq!(
+            diff("Faint: Summon one 1/1 Bee.", "Faint: Summon two 2/2 Bee."),
+            ["Attack 1→2", "Health 1→2", "summon.count 1→2"]
+        );
+        assert_eq!(
+            diff(
+                "Faint: Give one random friend +1 attack.",
+                "Faint: Give two random friends +2 attack."
+            ),
+            ["target_count 1→2", "Attack 1→2"]
+        );
+        assert_eq!(
+            diff(
+                "Sell: Stock one free Sleeping Pill.",
+                "Sell: Stock two Sleeping Pills."
+            ),
+            ["shop.count 1→2", "Removed shop.price_change free"]
+        );
+    }
+
+    #[test]
+    fn test_diff_entity_order() {
+        let old = Ability::parse("Faint: Give one random friend +1 attack and +2 health.").unwrap();
+        let new = Ability::parse("Faint: Give one random friend +2 health and +1 attack.").unwrap();
+        assert!(old[0].diff(&new[0]).is_empty());
+
+        let new = Ability::parse("Faint: Give one random friend +3 health and +1 attack.").unwrap();
+        assert_eq!(
+            old[0]
+                .diff(&new[0])
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["Health 2→3"]
+        );
+    }
+
+    #[test]
+    fn test_diff_text() {
+        let diff = |old: &str, new: &str| -> Vec<String> {
+            let old = Ability::parse(old).unwrap();
+            let new = Ability::parse(new).unwrap();
+            old[0]
+                .diff(&new[0])
+                .iter()
+                .map(ToString::to_string)
+                .collect()
+        };
+        assert_eq!(
+            diff(
+                "Faint: Gain attack equal to 2x its health.",
+                "Faint: Gain attack equal to 3x its health."
+            ),
+            ["amounts changed from attack equal to 2x 100% of triggering health to attack equal to 3x 100% of triggering health"]
+        );
+        assert_eq!(
+            diff(
+                "Friend ahead faints: Gain +1 attack until end of battle.",
+                "Two friends faint: Gain +1 attack. Works 2 times per battle."
+            ),
+            [
+                "Removed trigger.prim_pos ahead",
+                "Added trigger.number 2",
+                "trigger.count changed from once to 2 at once",
+                "Added usage works 2 times per battle",
+                "Removed duration until end of battle",
+            ]
+        );
+        assert_eq!(
+            diff(
+                "Faint: Summon a lower tier pet.",
+                "Faint: Summon a higher tier pet."
+            ),
+            ["comparisons changed from affected tier < this to affected tier > this"]
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use std::fmt::Display;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Duration of an effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Duration {
+    /// No duration given.
+    #[default]
+    Unspecified,
+    /// Until the end of battle.
+    /// - ex. `until end of battle`
+    EndOfBattle,
+    /// Until the end of turn.
+    /// - ex. `until end of turn`, `this turn`
+    EndOfTurn,
+    /// During the next battle.
+    /// - ex. `next battle`
+    NextBattle,
+    /// For the next attack.
+    /// - ex. `for the next attack`
+    NextAttack,
+    /// Permanent.
+    /// - ex. `permanently`
+    Permanent,
+}
+
+impl Duration {
+    /// Check if effect with duration is temporary.
+    pub fn is_temp(&self) -> bool {
+        matches!(
+            self,
+            Duration::EndOfBattle
+                | Duration::EndOfTurn
+                | Duration::NextBattle
+                | Duration::NextAttack
+        )
+    }
+}
+
+impl Display for Duration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Duration::Unspecified => "unspecified",
+            Duration::EndOfBattle => "until end of battle",
+            Duration::EndOfTurn => "until end of turn",
+            Duration::NextBattle => "next battle",
+            Duration::NextAttack => "next attack",
+            Duration::Permanent => "permanently",
+        })
+    }
+}
@@ -1,44 +1,104 @@
-use std::iter::Peekable;
+use std::{iter::Peekable, ops::Deref};
 
-use anyhow::{bail, Context};
+use anyhow::bail;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    compare::{split_stat, CompareOp, Comparison, Operand},
+    condition::Condition,
+    duration::Duration,
+    lint::{Diagnostic, GainOnSelf, GiveHasPosition, Rule},
+    reference::AbilityRef,
+    shop::{PriceChange, ShopEffect},
+    span::{consumed_span, EffectSpans},
+    summon::SummonSpec,
     token::{
         actions::ActionType, attribute::EntityType, logic::LogicType, numeric::NumericType,
-        position::PositionType, target::TargetType, types::TokenType, SAPTokens, Token,
+        position::PositionType, target::TargetType, types::TokenType, ParseNumber, SAPTokens,
+        Token,
     },
     trigger::EffectTrigger,
+    usage::{UsageLimit, UsagePeriod},
+    value::{create_equal_value, Amount},
 };
 
 /// A Super Auto Pets effect.
 /// - ex. `Gain +2 attack and +2 health.`
-#[derive(Debug, Default, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Effect<'src> {
     /// Effect trigger.
     pub trigger: Option<EffectTrigger<'src>>,
     /// Secondary effect trigger for conditional effects.
+    /// * For [`LogicType::If`] effects, all predicates are folded into one trigger. See [`Effect::condition`].
     pub cond_trigger: Option<EffectTrigger<'src>>,
+    /// Condition for effect to occur.
+    /// - ex. `If this has a level 3 friend and is in battle, ...`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub condition: Option<Condition<'src>>,
     /// Target of the effect.
     pub target: Option<TargetType>,
+    /// Number of targets affected.
+    /// * [`None`] if not given or the count is used by [`Effect::summon`], [`Effect::shop`], or [`Effect::ability_ref`].
+    /// - ex. `Give two random friends +1 attack.`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub target_count: Option<usize>,
     /// Affected entities.
-    #[serde(borrow)]
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub entities: Vec<EntityType<'src>>,
+    /// Amounts of affected stats.
+    /// - ex. `Gain attack equal to 2x its health.`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub amounts: Vec<Amount<'src>>,
+    /// Pets to summon.
+    /// - ex. `Summon one 2/2 Zombie Cricket.`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub summon: Option<SummonSpec<'src>>,
+    /// Shop effect.
+    /// - ex. `Discount shop food by 1 gold.`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub shop: Option<ShopEffect<'src>>,
+    /// Reference to another pet's ability.
+    /// - ex. `Copy the ability of the highest tier friend.`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub ability_ref: Option<AbilityRef<'src>>,
+    /// Stat comparisons filtering affected entities.
+    /// - ex. `Summon a lower tier pet.`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub comparisons: Vec<Comparison<'src>>,
+    /// Entities affected items must not be or have.
+    /// - ex. `Give two random friends without a perk +1 health.`
+    /// - ex. `non-Faint pets`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub excluded: Vec<EntityType<'src>>,
     /// Position of target to affect.
     pub position: Vec<PositionType>,
     /// Action to take.
     pub action: Option<ActionType>,
-    /// Number of uses of effect per trigger.
+    /// Number of uses of effect.
     /// * `None` indicates unlimited uses.
+    /// * Kept for compatibility and always matches [`Effect::usage`].
+    #[deprecated(note = "Use `Effect::usage` instead.")]
+    #[cfg_attr(feature = "serde", serde(default))]
     pub uses: Option<usize>,
+    /// Limit on the number of uses of effect.
+    /// - ex. `Works 2 times per battle.`
+    pub usage: Option<UsageLimit>,
+    /// Duration of effect.
+    /// - ex. `until end of battle`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub duration: Duration,
     /// If the effect is temporary or not.
+    /// * Kept for compatibility and always matches [`Effect::duration`]. See [`Effect::temp`].
+    #[deprecated(note = "Use `Effect::duration` instead.")]
+    #[cfg_attr(feature = "serde", serde(default))]
     pub temp: bool,
 }
 
 /// Macro to update `effect` if the effect is related to the maximum or minimum attack/health of something.
+/// * Evaluates to `true` if `effect` was updated.
 macro_rules! update_effect_max_min_stat_pos {
     ($tokens:ident, $effect:ident, atk = $attack_pos_type:expr, health = $health_pos_type:expr) => {
         // Check next token for most/least health/attack.
@@ -53,12 +113,14 @@ macro_rules! update_effect_max_min_stat_pos {
             .map(|token| &token.ttype)
         {
             Some(TokenType::Entity(EntityType::Attack(None))) => {
-                $effect.position.push($attack_pos_type)
+                $effect.position.push($attack_pos_type);
+                true
             }
             Some(TokenType::Entity(EntityType::Health(None))) => {
-                $effect.position.push($health_pos_type)
+                $effect.position.push($health_pos_type);
+                true
             }
-            _ => {}
+            _ => false,
         }
     };
 }
@@ -101,6 +163,8 @@ macro_rules! update_effect_trigger_from_token {
             }
             TokenType::Target(target) => $effect_trigger.target = Some(*target),
             TokenType::Action(action) => $effect_trigger.action = Some(*action),
+            // Negation cannot be kept by the trigger and is handled by the caller.
+            TokenType::Logic(LogicType::Not) => {}
             TokenType::Logic(logic) => {
                 $effect_trigger.logic = Some(*logic);
 
@@ -121,43 +185,131 @@ macro_rules! update_effect_trigger_from_token {
     };
 }
 
-/// Create [`EffectTrigger`] for a [`LogicType::If`] effect.
+/// Create [`EffectTrigger`] and [`Condition`] for a [`LogicType::If`] effect.
 /// * This should be invoked **before** the current [`Token`] has a [`Token::ttype`] of [`LogicType::ForEach`].
 /// * Consumes iterator until [`TokenType::Action`] is found.
+/// * Predicates are split on [`LogicType::And`] and [`LogicType::Or`].
+/// * Predicates with a [`LogicType::Not`] are negated.
+///     * ex. `If this has no perk, ...`
 ///
 /// ### Params
 /// * `tokens`: [`Peekable`] iterator of tokens.
 ///
 /// ### Returns
-/// * [`EffectTrigger`]
-fn create_if_cond<'src, T>(tokens: &mut Peekable<T>) -> Option<EffectTrigger<'src>>
+/// * [`EffectTrigger`] with all predicates folded into it and [`Condition`].
+fn create_if_cond<'a, 'src: 'a, T>(
+    tokens: &mut Peekable<T>,
+) -> Option<(EffectTrigger<'src>, Condition<'src>)>
 where
-    T: Iterator<Item = &'src Token<'src>>,
+    T: Iterator<Item = &'a Token<'src>>,
 {
     tokens.next_if(|token| matches!(token.ttype, TokenType::Logic(LogicType::If)))?;
 
-    let mut effect_trigger = EffectTrigger {
+    let mut pred = EffectTrigger {
         logic: Some(LogicType::If),
         ..Default::default()
     };
-    while let Some(token) = tokens.next_if(|token| !matches!(token.ttype, TokenType::Action(_))) {
-        update_effect_trigger_from_token!(tokens, token, effect_trigger);
+    let mut pred_tokens = vec![];
+    let mut preds = vec![];
+    let mut all_pred_tokens = vec![];
+    let mut pred_logic = vec![];
+    // If the current predicate is negated and if each predicate is negated.
+    let mut is_negated = false;
+    let mut all_negated = vec![];
+
+    // Battle outcomes are part of the condition rather than the effect action.
+    // ex. If you lost last battle, ...
+    while let Some(token) = tokens.next_if(|token| {
+        !matches!(
+            token.ttype,
+            TokenType::Action(action) if !matches!(action, ActionType::Win | ActionType::Lose)
+        )
+    }) {
+        if let TokenType::Logic(logic @ (LogicType::And | LogicType::Or)) = token.ttype {
+            // Part of comparison.
+            // ex. tier 3 or lower
+            let is_inclusive_cmp = logic == LogicType::Or
+                && tokens.peek().is_some_and(|token| {
+                    matches!(
+                        token.ttype,
+                        TokenType::Numeric(NumericType::LessEqual | NumericType::GreaterEqual)
+                    )
+                });
+            if is_inclusive_cmp {
+                pred_tokens.push(token);
+                continue;
+            }
+            // Start new predicate.
+            preds.push(std::mem::take(&mut pred));
+            all_pred_tokens.push(std::mem::take(&mut pred_tokens));
+            all_negated.push(std::mem::take(&mut is_negated));
+            pred_logic.push(logic);
+            continue;
+        }
+        if token.ttype == TokenType::Logic(LogicType::Not) {
+            is_negated = !is_negated;
+            continue;
+        }
+        pred_tokens.push(token);
+        update_effect_trigger_from_token!(tokens, token, pred);
+    }
+    preds.push(pred);
+    all_pred_tokens.push(pred_tokens);
+    all_negated.push(is_negated);
+
+    // Fold all predicates into one trigger.
+    let mut effect_trigger = EffectTrigger::default();
+    for pred in preds.iter() {
+        effect_trigger.action = pred.action.or(effect_trigger.action);
+        effect_trigger.number = pred.number.or(effect_trigger.number);
+        effect_trigger.entity = pred.entity.clone().or(effect_trigger.entity);
+        effect_trigger.target = pred.target.or(effect_trigger.target);
+        effect_trigger.logic = pred.logic.or(effect_trigger.logic);
+        for pos in [pred.prim_pos, pred.sec_pos].into_iter().flatten() {
+            if effect_trigger.prim_pos.is_none() {
+                effect_trigger.prim_pos = Some(pos)
+            } else if effect_trigger.sec_pos.is_none() {
+                effect_trigger.sec_pos = Some(pos)
+            }
+        }
     }
-    Some(effect_trigger)
+
+    // Use comparison if predicate compares stats.
+    let mut preds = preds.into_iter().zip(all_pred_tokens).zip(all_negated).map(
+        |((pred, pred_tokens), is_negated)| {
+            let cond =
+                Comparison::from_tokens(&pred_tokens, Operand::Position(PositionType::OnSelf))
+                    .map_or(Condition::Predicate(pred), Condition::Compare);
+            if is_negated {
+                cond.negate()
+            } else {
+                cond
+            }
+        },
+    );
+    let first_pred = preds.next()?;
+    let condition = Condition::from_predicates(first_pred, pred_logic.into_iter().zip(preds));
+    Some((effect_trigger, condition))
 }
 
 /// Create [`EffectTrigger`] for a [`LogicType::ForEach`] effect.
 /// * This should be invoked when the current [`Token`] has a [`Token::ttype`] of [`LogicType::ForEach`].
 /// * Consumes iterator until [`TokenType::EndText`] or [`TokenType::Logic(LogicType::To)`] are found.
+/// * A [`TokenType::Entity`] negated by [`LogicType::Not`] cannot be counted by a trigger.
+///     * ex. `for each friend without a perk`
 ///
 /// ### Params
 /// * `tokens`: [`Peekable`] iterator of tokens.
+/// * `unused`: Unused tokens.
 ///
 /// ### Returns
-/// * [`EffectTrigger`]
-fn create_foreach_cond<'src, T>(tokens: &mut Peekable<T>) -> EffectTrigger<'src>
+/// * [`EffectTrigger`] or error if a counted entity is negated.
+fn create_foreach_cond<'a, 'src: 'a, T>(
+    tokens: &mut Peekable<T>,
+    unused: &mut Vec<&'a Token<'src>>,
+) -> anyhow::Result<EffectTrigger<'src>>
 where
-    T: Iterator<Item = &'src Token<'src>>,
+    T: Iterator<Item = &'a Token<'src>>,
 {
     let mut effect_trigger = EffectTrigger {
         logic: Some(LogicType::ForEach),
@@ -171,9 +323,20 @@ where
             TokenType::EndText | TokenType::Logic(LogicType::To)
         )
     }) {
+        if token.ttype == TokenType::Logic(LogicType::Not) {
+            // Counting every item would reverse the meaning of the negation.
+            if let Some(TokenType::Entity(entity)) = tokens.peek().map(|token| &token.ttype) {
+                bail!(
+                    "Cannot count items without {entity:?} for each. {}",
+                    token.metadata
+                )
+            }
+            unused.push(token);
+            continue;
+        }
         update_effect_trigger_from_token!(tokens, token, effect_trigger);
     }
-    effect_trigger
+    Ok(effect_trigger)
 }
 impl<'src> Effect<'src> {
     /// Initialize a new SAP effect.
@@ -186,6 +349,7 @@ impl<'src> Effect<'src> {
     ///
     /// ### Returns
     /// * One or more [`Effect`]s.
+    ///     * Alternative effects joined by `or` are flattened. Use [`EffectGroup::new`] to keep them apart.
     ///
     /// ```
     /// use saplex::{SAPText, EffectTrigger, Effect};
@@ -210,13 +374,361 @@ impl<'src> Effect<'src> {
     /// ```
     pub fn new(
         trigger: Option<EffectTrigger<'src>>,
-        tokens: &'src SAPTokens,
+        tokens: &SAPTokens<'src>,
+    ) -> anyhow::Result<Vec<Self>> {
+        let group = EffectGroup::parse(trigger, tokens, &mut vec![], &mut vec![], true)?;
+        check_action_rules(&group)?;
+        Ok(group.into_effects())
+    }
+
+    /// Initialize a new SAP effect and get the tokens not used to create it.
+    /// * See [`Effect::new`].
+    ///
+    /// ### Params
+    /// * `trigger`
+    ///     * Optional [`EffectTrigger`]
+    /// * `tokens`
+    ///     * Effect text [`Token`]s as [`SAPTokens`].
+    ///
+    /// ### Returns
+    /// * One or more [`Effect`]s and unused [`Token`]s in text order.
+    ///     * [`TokenType::EndText`] is never included.
+    ///
+    /// ```
+    /// use saplex::{SAPText, Effect};
+    ///
+    /// let effect_txt = SAPText::new("Gain two +1 attack.");
+    /// let tokens = effect_txt.tokenize().unwrap();
+    /// let (_, unused) = Effect::new_with_unused(None, &tokens).unwrap();
+    ///
+    /// assert_eq!(unused.len(), 1);
+    /// assert_eq!(unused[0].text, "two");
+    /// ```
+    pub fn new_with_unused<'t>(
+        trigger: Option<EffectTrigger<'src>>,
+        tokens: &'t SAPTokens<'src>,
+    ) -> anyhow::Result<(Vec<Self>, Vec<&'t Token<'src>>)> {
+        let mut unused = vec![];
+        let group = EffectGroup::parse(trigger, tokens, &mut unused, &mut vec![], true)?;
+        check_action_rules(&group)?;
+        Ok((group.into_effects(), unused))
+    }
+
+    /// Initialize a new SAP effect and get the source spans of its fields.
+    /// * See [`Effect::new`].
+    /// * Invalid actions are not errors so they can be reported with spans. See [`Linter::lint`](crate::lint::Linter::lint).
+    ///
+    /// ### Params
+    /// * `trigger`
+    ///     * Optional [`EffectTrigger`]
+    /// * `tokens`
+    ///     * Effect text [`Token`]s as [`SAPTokens`].
+    ///
+    /// ### Returns
+    /// * One or more [`Effect`]s each with its [`EffectSpans`].
+    ///
+    /// ```
+    /// use saplex::{SAPText, Effect};
+    ///
+    /// let effect_txt = SAPText::new("Give one random friend +1 attack.");
+    /// let tokens = effect_txt.tokenize().unwrap();
+    /// let effects = Effect::new_with_spans(None, &tokens).unwrap();
+    /// let (_, spans) = &effects[0];
+    ///
+    /// let action = spans.action.as_ref().unwrap();
+    /// assert_eq!(&effect_txt.effect[action.start..action.current], "Give");
+    /// let attack = &spans.entities[0];
+    /// assert_eq!(&effect_txt.effect[attack.start..attack.current], "+1 attack");
+    /// ```
+    pub fn new_with_spans(
+        trigger: Option<EffectTrigger<'src>>,
+        tokens: &SAPTokens<'src>,
+    ) -> anyhow::Result<Vec<(Self, EffectSpans)>> {
+        let mut spans = vec![];
+        let group = EffectGroup::parse(trigger, tokens, &mut vec![], &mut spans, true)?;
+        Ok(group.into_effects().into_iter().zip(spans).collect())
+    }
+
+    /// Initialize a new SAP effect, erroring if any meaningful tokens are not used.
+    /// * Filler tokens are ignored. See [`Token::is_filler`].
+    ///
+    /// ### Params
+    /// * `trigger`
+    ///     * Optional [`EffectTrigger`]
+    /// * `tokens`
+    ///     * Effect text [`Token`]s as [`SAPTokens`].
+    ///
+    /// ### Returns
+    /// * One or more [`Effect`]s.
+    /// * Errors if any meaningful token is unused.
+    ///
+    /// ```
+    /// use saplex::{SAPText, Effect};
+    ///
+    /// let effect_txt = SAPText::new("Give two random friends +1 attack.");
+    /// let tokens = effect_txt.tokenize().unwrap();
+    /// let effects = Effect::new_strict(None, &tokens).unwrap();
+    /// assert_eq!(effects[0].target_count, Some(2));
+    ///
+    /// let effect_txt = SAPText::new("Gain two +1 attack.");
+    /// let tokens = effect_txt.tokenize().unwrap();
+    /// assert!(Effect::new(None, &tokens).is_ok());
+    /// assert!(Effect::new_strict(None, &tokens).is_err());
+    /// ```
+    pub fn new_strict(
+        trigger: Option<EffectTrigger<'src>>,
+        tokens: &SAPTokens<'src>,
     ) -> anyhow::Result<Vec<Self>> {
+        let (effects, unused) = Effect::new_with_unused(trigger, tokens)?;
+        let unused: Vec<String> = unused
+            .into_iter()
+            .filter(|token| !token.is_filler())
+            .map(|token| format!("{:?} ({})", token.ttype, token.text))
+            .collect();
+        if !unused.is_empty() {
+            bail!("Unused tokens in effect: {}", unused.join(", "))
+        }
+        Ok(effects)
+    }
+
+    /// Check if effect is temporary.
+    /// * See [`Effect::duration`].
+    pub fn temp(&self) -> bool {
+        self.duration.is_temp()
+    }
+
+    /// Finalize effect after all tokens are consumed.
+    /// * Updates [`Effect::temp`], [`Effect::uses`], [`Effect::amounts`], [`Effect::summon`], [`Effect::shop`], and [`Effect::ability_ref`] and validates the action.
+    ///
+    /// ### Params
+    /// * `count`
+    ///     * Number of items affected by the effect, if given.
+    fn finalize(&mut self, count: Option<usize>) -> anyhow::Result<()> {
+        #[allow(deprecated)]
+        {
+            self.temp = self.duration.is_temp();
+            self.uses = self.usage.map(|usage| usage.count);
+        }
+        // Implicit positions added by validation are not targets that can be counted.
+        let has_target = self.target.is_some() || !self.position.is_empty();
+        self.update_amounts();
+        self.validate_action()?;
+        self.summon = SummonSpec::from_effect(self, count);
+        self.shop = ShopEffect::from_effect(self, count);
+        self.ability_ref = AbilityRef::from_effect(self, count);
+        self.target_count = count.filter(|_| {
+            has_target && self.summon.is_none() && self.shop.is_none() && self.ability_ref.is_none()
+        });
+        Ok(())
+    }
+
+    /// Amounts from [`Effect::entities`].
+    /// * Amounts are scaled if [`Effect::cond_trigger`] is a [`LogicType::ForEach`] trigger.
+    pub(crate) fn derived_amounts(&self) -> Vec<Amount<'src>> {
+        let mut amounts: Vec<Amount> = self
+            .entities
+            .iter()
+            .filter_map(|entity| Amount::from_entity(entity, self.action))
+            .collect();
+        self.scale_amounts(&mut amounts);
+        amounts
+    }
+
+    /// Scale amounts if [`Effect::cond_trigger`] is a [`LogicType::ForEach`] trigger.
+    fn scale_amounts(&self, amounts: &mut [Amount<'src>]) {
+        if let Some(count) = self
+            .cond_trigger
+            .as_ref()
+            .filter(|trigger| trigger.logic == Some(LogicType::ForEach))
+        {
+            for amount in amounts.iter_mut() {
+                amount.scale(count)
+            }
+        }
+    }
+
+    /// Update [`Effect::amounts`] with amounts from [`Effect::entities`].
+    /// * Derived amounts come first. See [`Effect::derived_amounts`].
+    /// * Amounts are scaled if [`Effect::cond_trigger`] is a [`LogicType::ForEach`] trigger.
+    pub(crate) fn update_amounts(&mut self) {
+        let mut explicit = std::mem::take(&mut self.amounts);
+        self.scale_amounts(&mut explicit);
+        self.amounts = self.derived_amounts();
+        self.amounts.append(&mut explicit);
+    }
+
+    /// Check if effect affects trumpets rather than pets.
+    pub(crate) fn is_trumpet_effect(&self) -> bool {
+        self.entities
+            .iter()
+            .any(|e| matches!(e, EntityType::Trumpet(_) | EntityType::TrumpetPercent(_)))
+    }
+
+    /// Validate action
+    /// * Adds implicit positions.
+    /// * Rules making an action invalid are checked separately. See [`check_action_rules`].
+    fn validate_action(&mut self) -> anyhow::Result<()> {
+        match self.action {
+            // Add implicit position if none given.
+            Some(ActionType::Gain) if self.position.is_empty() && !self.is_trumpet_effect() => {
+                self.position.push(PositionType::OnSelf)
+            }
+            // Assume on self if no positions.
+            Some(ActionType::Summon) if self.position.is_empty() => {
+                self.position.push(PositionType::OnSelf)
+            }
+            // Damage taken by this pet.
+            // ex. Take 2 less damage.
+            Some(ActionType::Take) if self.position.is_empty() && self.target.is_none() => {
+                self.position.push(PositionType::OnSelf)
+            }
+            Some(_) => {}
+            None => {
+                // Cannot have conditional without an action.
+                if let Some(cond) = &self.cond_trigger {
+                    bail!("Condition must be followed by an action. ({cond:?})")
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A group of [`Effect`]s created from one effect text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EffectGroup<'src> {
+    /// All effects occur.
+    /// - ex. `Gain +1 attack and give the friend behind +1 health.`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    All(Vec<Effect<'src>>),
+    /// Only one of the effects occur.
+    /// - ex. `Gain +1 attack or +1 health.`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    OneOf(Vec<Effect<'src>>),
+}
+
+impl<'src> Deref for EffectGroup<'src> {
+    type Target = [Effect<'src>];
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            EffectGroup::All(effects) | EffectGroup::OneOf(effects) => effects,
+        }
+    }
+}
+
+impl<'src> EffectGroup<'src> {
+    /// Initialize a new group of SAP effects.
+    /// * Effects joined by `and` all occur.
+    /// * Effects or entities joined by `or` are alternatives and only one occurs.
+    ///
+    /// ### Params
+    /// * `trigger`
+    ///     * Optional [`EffectTrigger`]
+    /// * `tokens`
+    ///     * Effect text [`Token`]s as [`SAPTokens`].
+    ///
+    /// ### Returns
+    /// * [`EffectGroup`]
+    /// * Errors if `and` and `or` are both used to join effects.
+    ///
+    /// ```
+    /// use saplex::{SAPText, EffectGroup};
+    ///
+    /// let effect_txt = SAPText::new("Gain +1 attack or +1 health.");
+    /// let tokens = effect_txt.tokenize().unwrap();
+    /// let group = EffectGroup::new(None, &tokens).unwrap();
+    ///
+    /// assert!(matches!(group, EffectGroup::OneOf(_)));
+    /// assert_eq!(group.len(), 2);
+    /// ```
+    pub fn new(
+        trigger: Option<EffectTrigger<'src>>,
+        tokens: &SAPTokens<'src>,
+    ) -> anyhow::Result<Self> {
+        let group = EffectGroup::parse(trigger, tokens, &mut vec![], &mut vec![], false)?;
+        check_action_rules(&group)?;
+        Ok(group)
+    }
+
+    /// Initialize a new group of SAP effects and get the tokens not used to create it.
+    /// * See [`EffectGroup::new`].
+    ///
+    /// ### Params
+    /// * `trigger`
+    ///     * Optional [`EffectTrigger`]
+    /// * `tokens`
+    ///     * Effect text [`Token`]s as [`SAPTokens`].
+    ///
+    /// ### Returns
+    /// * [`EffectGroup`] and unused [`Token`]s in text order.
+    ///     * [`TokenType::EndText`] is never included.
+    pub fn new_with_unused<'t>(
+        trigger: Option<EffectTrigger<'src>>,
+        tokens: &'t SAPTokens<'src>,
+    ) -> anyhow::Result<(Self, Vec<&'t Token<'src>>)> {
+        let mut unused = vec![];
+        let group = EffectGroup::parse(trigger, tokens, &mut unused, &mut vec![], false)?;
+        check_action_rules(&group)?;
+        Ok((group, unused))
+    }
+
+    /// Initialize a new group of SAP effects and get the source spans of each effect's fields.
+    /// * See [`EffectGroup::new`].
+    /// * Invalid actions are not errors so they can be reported with spans. See [`Linter::lint`](crate::lint::Linter::lint).
+    ///
+    /// ### Params
+    /// * `trigger`
+    ///     * Optional [`EffectTrigger`]
+    /// * `tokens`
+    ///     * Effect text [`Token`]s as [`SAPTokens`].
+    ///
+    /// ### Returns
+    /// * [`EffectGroup`] and one [`EffectSpans`] per effect in the group.
+    pub fn new_with_spans(
+        trigger: Option<EffectTrigger<'src>>,
+        tokens: &SAPTokens<'src>,
+    ) -> anyhow::Result<(Self, Vec<EffectSpans>)> {
+        let mut spans = vec![];
+        let group = EffectGroup::parse(trigger, tokens, &mut vec![], &mut spans, false)?;
+        Ok((group, spans))
+    }
+
+    /// Parse a group of SAP effects.
+    /// * Tokens in the effect text not used are added to `unused`.
+    /// * Tokens consumed by a condition or [`LogicType::ForEach`] trigger are considered used.
+    /// * Source spans of each effect are added to `spans`.
+    /// * Effects joined by both `and` and `or` are an error unless `allow_mixed_logic` is set.
+    ///     * Used by [`Effect::new`] which flattens the group.
+    fn parse<'t>(
+        trigger: Option<EffectTrigger<'src>>,
+        tokens: &'t SAPTokens<'src>,
+        unused: &mut Vec<&'t Token<'src>>,
+        spans: &mut Vec<EffectSpans>,
+        allow_mixed_logic: bool,
+    ) -> anyhow::Result<Self> {
+        let all_tokens = tokens;
         let mut tokens = tokens.iter().peekable();
         let mut effects: Vec<Effect> = vec![];
+        // Logic joining effects.
+        let mut group_logic: Option<LogicType> = None;
+        // Indices of entities that are alternatives of each other in the current effect.
+        let mut alt_idxs: Vec<usize> = vec![];
+        // Number of items affected by the current effect and its token.
+        let mut count: Option<(usize, &Token)> = None;
+        // Construct secondary trigger and condition for If, if possible.
+        let (cond_trigger, condition) = create_if_cond(&mut tokens).unzip();
+        let mut effect_spans = EffectSpans::default();
+        if let (Some(_), Some(first_token)) = (&cond_trigger, all_tokens.first()) {
+            let span = consumed_span(all_tokens, first_token, tokens.peek().copied());
+            effect_spans.cond_trigger = Some(span.clone());
+            effect_spans.condition = Some(span);
+        }
         let mut effect = Effect {
-            // Construct secondary trigger for If, if possible.
-            cond_trigger: create_if_cond(&mut tokens),
+            cond_trigger,
+            condition,
             ..Default::default()
         };
         effect.trigger = trigger.clone();
@@ -224,22 +736,153 @@ impl<'src> Effect<'src> {
         while let Some(token) = tokens.next() {
             match &token.ttype {
                 TokenType::Numeric(NumericType::Max) => {
-                    update_effect_max_min_stat_pos!(
+                    if update_effect_max_min_stat_pos!(
                         tokens,
                         effect,
                         atk = PositionType::Strongest,
                         health = PositionType::Healthiest
-                    );
+                    ) {
+                        effect_spans.position.push(Some(consumed_span(
+                            all_tokens,
+                            token,
+                            tokens.peek().copied(),
+                        )))
+                    } else {
+                        unused.push(token)
+                    }
                 }
                 TokenType::Numeric(NumericType::Min) => {
-                    update_effect_max_min_stat_pos!(
+                    if update_effect_max_min_stat_pos!(
                         tokens,
                         effect,
                         atk = PositionType::Weakest,
                         health = PositionType::Illest
-                    );
+                    ) {
+                        effect_spans.position.push(Some(consumed_span(
+                            all_tokens,
+                            token,
+                            tokens.peek().copied(),
+                        )))
+                    } else {
+                        unused.push(token)
+                    }
+                }
+                // Compare stat of affected item to this pet.
+                // ex. lower tier pet
+                TokenType::Numeric(num @ (NumericType::LessEqual | NumericType::GreaterEqual)) => {
+                    let stat = tokens.peek().and_then(|token| match &token.ttype {
+                        TokenType::Entity(entity) => split_stat(entity),
+                        _ => None,
+                    });
+                    if let (Some((stat, _)), Some(op)) =
+                        (stat, CompareOp::from_numeric(*num, false))
+                    {
+                        tokens.next();
+                        effect.comparisons.push(Comparison {
+                            lhs: Operand::Affected,
+                            op,
+                            stat,
+                            rhs: Operand::Position(PositionType::OnSelf),
+                        });
+                        effect_spans.comparisons.push(consumed_span(
+                            all_tokens,
+                            token,
+                            tokens.peek().copied(),
+                        ))
+                    } else {
+                        unused.push(token)
+                    }
+                }
+                // Amount derived from some stat.
+                // ex. attack equal to 2x its health
+                TokenType::Numeric(NumericType::Equal) => {
+                    let stat = effect.entities.last().and_then(split_stat);
+                    // Create value from a copy so no tokens are consumed on failure.
+                    let mut value_tokens = tokens.clone();
+                    let mut value_unused = vec![];
+                    match (
+                        stat,
+                        create_equal_value(&mut value_tokens, &mut value_unused),
+                    ) {
+                        (Some((stat, None)), Some(value)) => {
+                            tokens = value_tokens;
+                            unused.append(&mut value_unused);
+                            effect.amounts.push(Amount { stat, value });
+                            effect_spans.amounts.push(consumed_span(
+                                all_tokens,
+                                token,
+                                tokens.peek().copied(),
+                            ))
+                        }
+                        // Value of a stat that already has an amount cannot be used.
+                        // ex. +2 attack equal to its health
+                        (_, Some(_)) => {
+                            let n_consumed = tokens.len() - value_tokens.len();
+                            unused.push(token);
+                            unused.extend(tokens.by_ref().take(n_consumed))
+                        }
+                        // No value so remaining tokens are parsed.
+                        (_, None) => unused.push(token),
+                    }
                 }
-                TokenType::Numeric(_) => {}
+                // Change in some stat.
+                // ex. Take 2 less damage.
+                TokenType::Numeric(NumericType::Number(Some(num)))
+                    if matches!(
+                        tokens.peek().map(|token| &token.ttype),
+                        Some(TokenType::Numeric(NumericType::Plus | NumericType::Minus))
+                    ) =>
+                {
+                    // Safe to unwrap as peeked.
+                    let sign_token = tokens.next().unwrap();
+                    let stat = tokens
+                        .peek()
+                        .and_then(|token| match &token.ttype {
+                            TokenType::Entity(entity) => split_stat(entity),
+                            _ => None,
+                        })
+                        .filter(|(_, value)| value.is_none());
+                    if let Some((mut stat, _)) = stat {
+                        tokens.next();
+                        let num = if sign_token.ttype == TokenType::Numeric(NumericType::Minus) {
+                            -num
+                        } else {
+                            *num
+                        };
+                        stat.parse_num_str(&num.to_string())?;
+                        effect.entities.push(stat);
+                        effect_spans.entities.push(consumed_span(
+                            all_tokens,
+                            token,
+                            tokens.peek().copied(),
+                        ))
+                    } else {
+                        unused.extend([token, sign_token])
+                    }
+                }
+                // ex. Summon two 2/2 Rams.
+                TokenType::Numeric(NumericType::Number(Some(num))) => match usize::try_from(*num) {
+                    Ok(num) if count.is_none() && effect.action.is_some() => {
+                        count = Some((num, token));
+                        effect_spans.count = Some(token.metadata.clone())
+                    }
+                    _ => unused.push(token),
+                },
+                // ex. Stock a free Apple.
+                TokenType::Numeric(NumericType::Article)
+                    if count.is_none() && effect.action.is_some() =>
+                {
+                    count = Some((1, token));
+                    effect_spans.count = Some(token.metadata.clone())
+                }
+                // ex. Activate the ability of the friend ahead 2 times.
+                TokenType::Numeric(NumericType::Multiplier(Some(num)))
+                    if count.is_none() && effect.action == Some(ActionType::Activate) =>
+                {
+                    count = Some((usize::try_from(*num)?, token));
+                    effect_spans.count = Some(token.metadata.clone())
+                }
+                TokenType::Numeric(_) => unused.push(token),
                 TokenType::Entity(entity) => {
                     // Consume next token if damage attribute.
                     // This is attack/attack perc damage.
@@ -248,134 +891,397 @@ impl<'src> Effect<'src> {
                             matches!(token.ttype, TokenType::Entity(EntityType::Damage(None)))
                         });
                     }
-                    effect.entities.push(entity.clone())
+                    effect.entities.push(entity.clone());
+                    effect_spans.entities.push(consumed_span(
+                        all_tokens,
+                        token,
+                        tokens.peek().copied(),
+                    ))
                 }
                 TokenType::EndText => {}
-                TokenType::Position(pos) => effect.position.push(*pos),
-                TokenType::Target(target) => effect.target = Some(*target),
+                // ex. this turn
+                TokenType::Position(PositionType::OnSelf)
+                    if tokens
+                        .next_if(|token| token.ttype == TokenType::Entity(EntityType::Turn(None)))
+                        .is_some() =>
+                {
+                    effect.duration = Duration::EndOfTurn;
+                    effect_spans.duration =
+                        Some(consumed_span(all_tokens, token, tokens.peek().copied()))
+                }
+                TokenType::Position(pos) => {
+                    effect.position.push(*pos);
+                    effect_spans.position.push(Some(token.metadata.clone()))
+                }
+                TokenType::Target(target) => {
+                    effect.target = Some(*target);
+                    effect_spans.target = Some(token.metadata.clone())
+                }
                 // Create new effect trigger for for each effects.
                 // We cannot create multiple effects since we won't know stats/attributes of pets until runtime.
                 TokenType::Logic(LogicType::ForEach) => {
-                    effect.cond_trigger = Some(create_foreach_cond(&mut tokens));
+                    effect.cond_trigger = Some(create_foreach_cond(&mut tokens, unused)?);
+                    effect_spans.cond_trigger =
+                        Some(consumed_span(all_tokens, token, tokens.peek().copied()))
                 }
                 // Temp effect.
                 TokenType::Logic(LogicType::Until) => {
-                    // Must be until, end, and battle(none)
-                    effect.temp = matches_peek_next!(
+                    // Must be until, end, and battle(none) or turn(none)
+                    let end_token = matches_peek_next!(
                         tokens,
                         |token| token.ttype == TokenType::Logic(LogicType::End),
-                        |token| token.ttype == TokenType::Entity(EntityType::Battle(None))
-                    )
-                    .is_some();
+                        |token| matches!(
+                            token.ttype,
+                            TokenType::Entity(EntityType::Battle(None) | EntityType::Turn(None))
+                        )
+                    );
+                    match end_token.map(|token| &token.ttype) {
+                        Some(TokenType::Entity(EntityType::Battle(_))) => {
+                            effect.duration = Duration::EndOfBattle
+                        }
+                        Some(TokenType::Entity(EntityType::Turn(_))) => {
+                            effect.duration = Duration::EndOfTurn
+                        }
+                        _ => {
+                            unused.push(token);
+                            continue;
+                        }
+                    }
+                    effect_spans.duration =
+                        Some(consumed_span(all_tokens, token, tokens.peek().copied()))
+                }
+                // ex. next battle
+                TokenType::Logic(LogicType::Next) => {
+                    let next_token = tokens.next_if(|token| {
+                        matches!(
+                            token.ttype,
+                            TokenType::Entity(EntityType::Battle(None) | EntityType::Attack(None))
+                        )
+                    });
+                    match next_token.map(|token| &token.ttype) {
+                        Some(TokenType::Entity(EntityType::Battle(_))) => {
+                            effect.duration = Duration::NextBattle
+                        }
+                        Some(TokenType::Entity(EntityType::Attack(_))) => {
+                            effect.duration = Duration::NextAttack
+                        }
+                        _ => {
+                            unused.push(token);
+                            continue;
+                        }
+                    }
+                    effect_spans.duration =
+                        Some(consumed_span(all_tokens, token, tokens.peek().copied()))
+                }
+                TokenType::Logic(LogicType::Permanent) => {
+                    effect.duration = Duration::Permanent;
+                    effect_spans.duration = Some(token.metadata.clone())
+                }
+                // ex. Stock a free Apple.
+                TokenType::Logic(LogicType::Free)
+                    if effect.action.is_some_and(|action| action.is_shop_effect()) =>
+                {
+                    effect.shop = Some(ShopEffect {
+                        price_change: Some(PriceChange::Free),
+                        ..Default::default()
+                    })
                 }
                 // Multi-effect
-                TokenType::Logic(LogicType::And | LogicType::Or) => {
-                    // If next token is action, create new effect.
-                    if let Some(TokenType::Action(_)) = tokens.peek().map(|token| &token.ttype) {
-                        let mut new_effect = Effect {
-                            trigger: trigger.clone(),
-                            ..Default::default()
-                        };
-                        std::mem::swap(&mut effect, &mut new_effect);
+                TokenType::Logic(logic @ (LogicType::And | LogicType::Or | LogicType::Then)) => {
+                    match tokens.peek().map(|token| &token.ttype) {
+                        // If next token is action, create new effect.
+                        // ex. Gain +1 attack, then give the friend ahead +1 health.
+                        Some(TokenType::Action(_)) => {
+                            // Effects in sequence all occur.
+                            let logic = if *logic == LogicType::Then {
+                                LogicType::And
+                            } else {
+                                *logic
+                            };
+                            update_group_logic(&mut group_logic, logic, allow_mixed_logic)?;
+                            let mut new_effect = Effect {
+                                trigger: trigger.clone(),
+                                ..Default::default()
+                            };
+                            std::mem::swap(&mut effect, &mut new_effect);
 
-                        new_effect.validate_action()?;
-                        effects.push(new_effect)
+                            push_alternatives(
+                                (&mut effects, spans),
+                                (new_effect, std::mem::take(&mut effect_spans)),
+                                &alt_idxs,
+                                count.take(),
+                                unused,
+                            )?;
+                            alt_idxs.clear();
+                        }
+                        // Compare stat of affected item to a number.
+                        // ex. tier 3 or lower
+                        Some(TokenType::Numeric(
+                            num @ (NumericType::LessEqual | NumericType::GreaterEqual),
+                        )) if *logic == LogicType::Or => {
+                            let op = CompareOp::from_numeric(*num, true);
+                            let stat = effect.entities.last().and_then(split_stat);
+                            if let (Some(op), Some((stat, Some(value)))) = (op, stat) {
+                                tokens.next();
+                                effect.entities.pop();
+                                effect.comparisons.push(Comparison {
+                                    lhs: Operand::Affected,
+                                    op,
+                                    stat,
+                                    rhs: Operand::Number(value),
+                                });
+                                let span = consumed_span(all_tokens, token, tokens.peek().copied());
+                                let span = match effect_spans.entities.pop() {
+                                    Some(entity_span) => entity_span.join(&span),
+                                    None => span,
+                                };
+                                effect_spans.comparisons.push(span)
+                            } else {
+                                unused.push(token)
+                            }
+                        }
+                        // Alternative entities of the same action.
+                        // ex. +1 attack or +1 health
+                        Some(TokenType::Entity(_))
+                            if *logic == LogicType::Or && !effect.entities.is_empty() =>
+                        {
+                            update_group_logic(&mut group_logic, *logic, allow_mixed_logic)?;
+                            alt_idxs.push(effect.entities.len());
+                        }
+                        _ => unused.push(token),
                     }
                 }
-                TokenType::Logic(LogicType::Works) => {
-                    let next_usage_token = matches_peek_next!(tokens, |token| matches!(
-                        token.ttype,
-                        TokenType::Numeric(NumericType::Multiplier(_))
-                    ));
-                    if let Some(TokenType::Numeric(NumericType::Multiplier(Some(num_uses)))) =
-                        next_usage_token.map(|token| &token.ttype)
+                TokenType::Logic(logic @ (LogicType::Works | LogicType::Triggers)) => {
+                    let num_uses_token = tokens.next_if(|token| {
+                        matches!(
+                            token.ttype,
+                            TokenType::Numeric(NumericType::Multiplier(Some(_)))
+                        )
+                    });
+                    // Consume period token, if any.
+                    let period_token = tokens.next_if(|token| {
+                        matches!(
+                            token.ttype,
+                            TokenType::Entity(
+                                EntityType::Turn(None)
+                                    | EntityType::Battle(None)
+                                    | EntityType::Game(None)
+                            )
+                        )
+                    });
+                    let Some(TokenType::Numeric(NumericType::Multiplier(Some(num_uses)))) =
+                        num_uses_token.map(|token| &token.ttype)
+                    else {
+                        // No number of uses.
+                        // ex. Works per turn.
+                        unused.push(token);
+                        unused.extend(period_token);
+                        continue;
+                    };
+                    let period = match (period_token.map(|token| &token.ttype), logic) {
+                        (Some(TokenType::Entity(EntityType::Turn(_))), _) => UsagePeriod::Turn,
+                        (Some(TokenType::Entity(EntityType::Battle(_))), _) => UsagePeriod::Battle,
+                        (Some(TokenType::Entity(EntityType::Game(_))), _) => UsagePeriod::Game,
+                        (None, LogicType::Triggers) => UsagePeriod::Trigger,
+                        _ => UsagePeriod::Unspecified,
+                    };
+                    effect.usage = Some(UsageLimit {
+                        count: usize::try_from(*num_uses)?,
+                        period,
+                    });
+                    effect_spans.uses =
+                        Some(consumed_span(all_tokens, token, tokens.peek().copied()));
+                }
+                // Exclude entity from affected items.
+                // ex. friends without a perk
+                TokenType::Logic(LogicType::Not) => {
+                    match tokens
+                        .next_if(|token| matches!(token.ttype, TokenType::Entity(_)))
+                        .map(|token| &token.ttype)
                     {
-                        // Consume turns token stopping if not present.
-                        tokens
-                            .next_if(|token| {
-                                token.ttype == TokenType::Entity(EntityType::Turn(None))
-                            })
-                            .context("Must have Turns token after number of uses.")?;
-                        // Set number of uses.
-                        effect.uses = Some(usize::try_from(*num_uses)?)
+                        Some(TokenType::Entity(entity)) => {
+                            effect.excluded.push(entity.clone());
+                            effect_spans.excluded.push(consumed_span(
+                                all_tokens,
+                                token,
+                                tokens.peek().copied(),
+                            ))
+                        }
+                        _ => unused.push(token),
                     }
                 }
-                TokenType::Logic(_) => {}
-                TokenType::Action(action) => effect.action = Some(*action),
+                TokenType::Logic(_) => unused.push(token),
+                // Second action not joined to a new effect.
+                TokenType::Action(_) if effect.action.is_some() => unused.push(token),
+                TokenType::Action(action) => {
+                    effect.action = Some(*action);
+                    effect_spans.action = Some(token.metadata.clone())
+                }
             }
         }
 
-        effect.validate_action()?;
+        push_alternatives(
+            (&mut effects, spans),
+            (effect, effect_spans),
+            &alt_idxs,
+            count,
+            unused,
+        )?;
+        unused.sort_by_key(|token| token.metadata.start);
+
+        Ok(match group_logic {
+            Some(LogicType::Or) => EffectGroup::OneOf(effects),
+            _ => EffectGroup::All(effects),
+        })
+    }
+
+    /// Consume group returning its [`Effect`]s.
+    pub fn into_effects(self) -> Vec<Effect<'src>> {
+        match self {
+            EffectGroup::All(effects) | EffectGroup::OneOf(effects) => effects,
+        }
+    }
+}
+
+/// Split an item into one item per alternative entity.
+/// * Used for both an [`Effect`] and its [`EffectSpans`] so they are split the same way.
+/// * ex. `Gain +1 attack or +1 health.` -> `Gain +1 attack.` and `Gain +1 health.`
+///
+/// ### Params
+/// * `item`
+///     * Item to split.
+/// * `alt_idxs`
+///     * Indices in the item's entities where an alternative starts.
+/// * `entities`
+///     * Get entities of item.
+///
+/// ### Returns
+/// * One or more items.
+fn split_alternatives<T: Clone, E>(
+    mut item: T,
+    alt_idxs: &[usize],
+    entities: impl Fn(&mut T) -> &mut Vec<E>,
+) -> Vec<T> {
+    if alt_idxs.is_empty() {
+        return vec![item];
+    }
+    let mut all_entities = std::mem::take(entities(&mut item));
+    let mut alternatives = vec![];
+    // Split from the back so earlier indices stay valid.
+    for idx in alt_idxs.iter().rev() {
+        let mut alternative = item.clone();
+        *entities(&mut alternative) = all_entities.split_off(*idx);
+        alternatives.push(alternative)
+    }
+    *entities(&mut item) = all_entities;
+    alternatives.push(item);
+    alternatives.reverse();
+    alternatives
+}
+
+/// Finalize alternatives of an effect and add them to `effects`.
+/// * The count token is unused if no alternative has a target count or is a summon, shop, or ability reference effect.
+///
+/// ### Params
+/// * `(effects, spans)`
+///     * Finalized effects and their spans.
+/// * `(effect, effect_spans)`
+///     * Effect to split into alternatives and its spans. See [`split_alternatives`].
+/// * `alt_idxs`
+///     * Indices in [`Effect::entities`] where an alternative starts.
+/// * `count`
+///     * Number of items affected by the effect and its token, if given.
+/// * `unused`
+///     * Unused tokens.
+fn push_alternatives<'t, 'src>(
+    (effects, spans): (&mut Vec<Effect<'src>>, &mut Vec<EffectSpans>),
+    (effect, effect_spans): (Effect<'src>, EffectSpans),
+    alt_idxs: &[usize],
+    count: Option<(usize, &'t Token<'src>)>,
+    unused: &mut Vec<&'t Token<'src>>,
+) -> anyhow::Result<()> {
+    let (count, count_token) = count.unzip();
+    let mut is_count_used = false;
+    for (mut effect, mut effect_spans) in
+        split_alternatives(effect, alt_idxs, |effect| &mut effect.entities)
+            .into_iter()
+            .zip(split_alternatives(effect_spans, alt_idxs, |spans| {
+                &mut spans.entities
+            }))
+    {
+        effect.finalize(count)?;
+        effect_spans.finalize(&effect);
+        is_count_used |= effect.target_count.is_some()
+            || effect.summon.is_some()
+            || effect.shop.is_some()
+            || effect.ability_ref.is_some();
         effects.push(effect);
-        Ok(effects)
+        spans.push(effect_spans);
+    }
+    if let Some(token) = count_token.filter(|_| !is_count_used) {
+        unused.push(token)
     }
+    Ok(())
+}
 
-    /// Validate action
-    /// * [ActionType::Gain] should only be used on self.
-    /// * [ActionType::Give] can be used on other pets.
-    fn validate_action(&mut self) -> anyhow::Result<()> {
-        match self.action {
-            Some(ActionType::Gain) => {
-                // Add implicit position if none given.
-                let is_trumpet_effect = self
-                    .entities
-                    .iter()
-                    .any(|e| matches!(e, EntityType::Trumpet(_) | EntityType::TrumpetPercent(_)));
-                if self.position.is_empty() && !is_trumpet_effect {
-                    self.position.push(PositionType::OnSelf)
-                }
-                // Gain can only affect up to 1 pet.
-                if self.position.len() > 1 {
-                    bail!("Only one pet can be affected by {:?}", self.action)
-                }
-                // Gain effect can only affect self.
-                if self
-                    .position
-                    .first()
-                    .filter(|pos| **pos != PositionType::OnSelf)
-                    .is_some()
-                    && !is_trumpet_effect
-                {
-                    bail!("Gain action only affects Self pet.")
-                }
-            }
-            Some(ActionType::Give) => {
-                // Give must always have a position.
-                if self.position.is_empty() {
-                    bail!("Position must be given for {:?}", self.action)
-                }
-            }
-            Some(ActionType::Summon) => {
-                // Assume on self if no positions.
-                if self.position.is_empty() {
-                    self.position.push(PositionType::OnSelf)
-                }
-            }
-            Some(_) => {}
-            None => {
-                // Cannot have conditional without an action.
-                if let Some(cond) = &self.cond_trigger {
-                    bail!("Condition must be followed by an action. ({cond:?})")
-                }
-            }
+/// Update logic joining effects of an [`EffectGroup`].
+/// * Errors if `logic` differs from the previous logic and mixed logic is not allowed.
+fn update_group_logic(
+    group_logic: &mut Option<LogicType>,
+    logic: LogicType,
+    allow_mixed_logic: bool,
+) -> anyhow::Result<()> {
+    match group_logic.replace(logic) {
+        Some(prev_logic) if prev_logic != logic && !allow_mixed_logic => {
+            bail!("Cannot join effects with both {prev_logic:?} and {logic:?}.")
         }
+        _ => Ok(()),
+    }
+}
 
-        Ok(())
+/// Check effects against rules making their action invalid.
+/// * [ActionType::Gain] should only be used on self. See [`GainOnSelf`].
+/// * [ActionType::Give] can be used on other pets. See [`GiveHasPosition`].
+/// * Not checked when parsing with spans so they are reported with spans by [`Linter::lint`](crate::lint::Linter::lint).
+fn check_action_rules(effects: &[Effect]) -> anyhow::Result<()> {
+    let rules: [&dyn Rule; 2] = [&GainOnSelf, &GiveHasPosition];
+    if let Some(diagnostic) = effects
+        .iter()
+        .flat_map(|effect| {
+            rules
+                .iter()
+                .flat_map(|rule| rule.check(effect, &EffectSpans::default()))
+        })
+        .find(Diagnostic::is_error)
+    {
+        bail!("{}", diagnostic.message)
     }
+    Ok(())
 }
 
 #[cfg(test)]
+// Effects are compared with deprecated fields.
+#[allow(deprecated)]
 mod test {
     use crate::{
+        compare::{CompareOp, Comparison, Operand},
+        condition::Condition,
+        duration::Duration,
+        phase::Phase,
+        reference::{AbilityOwner, AbilityRef},
+        shop::{PriceChange, ShopEffect, ShopSlot},
+        summon::SummonSpec,
         token::{
-            actions::ActionType, attribute::EntityType, logic::LogicType, position::PositionType,
-            target::TargetType,
+            actions::ActionType, attribute::EntityType, logic::LogicType, numeric::NumericType,
+            percent::Percent, position::PositionType, target::TargetType, types::TokenType,
         },
-        trigger::EffectTrigger,
+        trigger::{EffectTrigger, TriggerCount},
+        usage::{UsageLimit, UsagePeriod},
+        value::{Amount, Value},
         SAPText,
     };
 
-    use super::Effect;
+    use super::{Effect, EffectGroup};
 
     #[test]
     fn test_interpret_conditional_has_effect() {
@@ -396,11 +1302,35 @@ mod test {
                     logic: Some(LogicType::Have),
                     ..Default::default()
                 }),
+                condition: Some(Condition::Predicate(EffectTrigger {
+                    entity: Some(EntityType::Level(Some(3))),
+                    target: Some(TargetType::Friend),
+                    logic: Some(LogicType::Have),
+                    ..Default::default()
+                })),
                 target: None,
+                target_count: None,
                 entities: vec![EntityType::Attack(Some(1)), EntityType::Health(Some(2))],
+                amounts: vec![
+                    Amount {
+                        stat: EntityType::Attack(None),
+                        value: Value::Literal(1)
+                    },
+                    Amount {
+                        stat: EntityType::Health(None),
+                        value: Value::Literal(2)
+                    }
+                ],
+                summon: None,
+                shop: None,
+                ability_ref: None,
+                comparisons: vec![],
+                excluded: vec![],
                 position: vec![PositionType::OnSelf],
                 action: Some(ActionType::Gain),
                 uses: None,
+                usage: None,
+                duration: Duration::Unspecified,
                 temp: false
             }
         )
@@ -421,17 +1351,46 @@ mod test {
                 cond_trigger: Some(EffectTrigger {
                     action: None,
                     number: None,
+                    count: TriggerCount::Once,
                     target: Some(TargetType::Friend),
                     prim_pos: Some(PositionType::OnSelf),
                     logic: Some(LogicType::Is),
                     sec_pos: Some(PositionType::Highest),
                     entity: Some(EntityType::Tier(None)),
                 }),
+                condition: Some(Condition::Predicate(EffectTrigger {
+                    action: None,
+                    number: None,
+                    count: TriggerCount::Once,
+                    target: Some(TargetType::Friend),
+                    prim_pos: Some(PositionType::OnSelf),
+                    logic: Some(LogicType::Is),
+                    sec_pos: Some(PositionType::Highest),
+                    entity: Some(EntityType::Tier(None)),
+                })),
                 target: None,
+                target_count: None,
                 entities: vec![EntityType::Attack(Some(1)), EntityType::Health(Some(2))],
+                amounts: vec![
+                    Amount {
+                        stat: EntityType::Attack(None),
+                        value: Value::Literal(1)
+                    },
+                    Amount {
+                        stat: EntityType::Health(None),
+                        value: Value::Literal(2)
+                    }
+                ],
+                summon: None,
+                shop: None,
+                ability_ref: None,
+                comparisons: vec![],
+                excluded: vec![],
                 position: vec![PositionType::OnSelf],
                 action: Some(ActionType::Gain),
                 uses: None,
+                usage: None,
+                duration: Duration::Unspecified,
                 temp: false
             }
         )
@@ -451,17 +1410,46 @@ mod test {
                 cond_trigger: Some(EffectTrigger {
                     action: None,
                     number: None,
+                    count: TriggerCount::Once,
                     entity: Some(EntityType::Battle(None)),
                     target: None,
                     logic: Some(LogicType::In),
                     prim_pos: None,
                     sec_pos: None
                 }),
+                condition: Some(Condition::Predicate(EffectTrigger {
+                    action: None,
+                    number: None,
+                    count: TriggerCount::Once,
+                    entity: Some(EntityType::Battle(None)),
+                    target: None,
+                    logic: Some(LogicType::In),
+                    prim_pos: None,
+                    sec_pos: None
+                })),
                 target: None,
+                target_count: None,
                 entities: vec![EntityType::Attack(Some(1)), EntityType::Health(Some(2))],
+                amounts: vec![
+                    Amount {
+                        stat: EntityType::Attack(None),
+                        value: Value::Literal(1)
+                    },
+                    Amount {
+                        stat: EntityType::Health(None),
+                        value: Value::Literal(2)
+                    }
+                ],
+                summon: None,
+                shop: None,
+                ability_ref: None,
+                comparisons: vec![],
+                excluded: vec![],
                 position: vec![PositionType::OnSelf],
                 action: Some(ActionType::Gain),
                 uses: None,
+                usage: None,
+                duration: Duration::Unspecified,
                 temp: false
             }
         )
@@ -483,18 +1471,41 @@ mod test {
                 cond_trigger: Some(EffectTrigger {
                     action: None,
                     number: None,
+                    count: TriggerCount::Once,
                     entity: Some(EntityType::Toy(None)),
                     target: None,
                     logic: Some(LogicType::Have),
                     prim_pos: None,
                     sec_pos: None,
                 },),
+                condition: Some(Condition::Predicate(EffectTrigger {
+                    action: None,
+                    number: None,
+                    count: TriggerCount::Once,
+                    entity: Some(EntityType::Toy(None)),
+                    target: None,
+                    logic: Some(LogicType::Have),
+                    prim_pos: None,
+                    sec_pos: None,
+                })),
                 target: Some(TargetType::Friend),
+                target_count: None,
                 entities: vec![EntityType::Health(Some(10))],
+                amounts: vec![Amount {
+                    stat: EntityType::Health(None),
+                    value: Value::Literal(10)
+                }],
+                summon: None,
+                shop: None,
+                ability_ref: None,
+                comparisons: vec![],
+                excluded: vec![],
                 position: vec![PositionType::Nearest, PositionType::Behind],
                 action: Some(ActionType::Give),
                 uses: None,
-                temp: false,
+                usage: None,
+                duration: Duration::Unspecified,
+                temp: false
             }
         )
     }
@@ -512,17 +1523,40 @@ mod test {
                 cond_trigger: Some(EffectTrigger {
                     action: None,
                     number: None,
+                    count: TriggerCount::Once,
                     entity: Some(EntityType::Ability(Some("Start of battle"))),
                     target: None,
                     logic: Some(LogicType::Have),
                     prim_pos: Some(PositionType::Trigger),
                     sec_pos: None
                 }),
+                condition: Some(Condition::Predicate(EffectTrigger {
+                    action: None,
+                    number: None,
+                    count: TriggerCount::Once,
+                    entity: Some(EntityType::Ability(Some("Start of battle"))),
+                    target: None,
+                    logic: Some(LogicType::Have),
+                    prim_pos: Some(PositionType::Trigger),
+                    sec_pos: None
+                })),
                 target: None,
+                target_count: None,
                 entities: vec![EntityType::Attack(Some(2))],
+                amounts: vec![Amount {
+                    stat: EntityType::Attack(None),
+                    value: Value::Literal(2)
+                }],
+                summon: None,
+                shop: None,
+                ability_ref: None,
+                comparisons: vec![],
+                excluded: vec![],
                 position: vec![PositionType::OnSelf],
                 action: Some(ActionType::Gain),
                 uses: None,
+                usage: None,
+                duration: Duration::Unspecified,
                 temp: false
             }
         )
@@ -530,12 +1564,46 @@ mod test {
 
     #[test]
     fn test_interpret_conditional_invalid_multi_use_effect() {
-        let invalid_effect_txt = SAPText::new(
-            "If it was a Faint pet, activate its ability again. Works 1 time per game.",
-        );
+        let invalid_effect_txt =
+            SAPText::new("If it was a Faint pet, activate its ability again. Works per turn.");
         let invalid_tokens = invalid_effect_txt.tokenize().unwrap();
-        // Works per turn only.
-        assert!(Effect::new(None, &invalid_tokens).is_err());
+        // Must have number of uses.
+        let (effects, unused) = Effect::new_with_unused(None, &invalid_tokens).unwrap();
+        assert_eq!(effects[0].usage, None);
+        assert_eq!(
+            unused.iter().map(|token| token.text).collect::<Vec<&str>>(),
+            ["Works", "turn"]
+        );
+        assert!(Effect::new_strict(None, &invalid_tokens).is_err());
+    }
+
+    #[test]
+    fn test_interpret_usage_limit_effect() {
+        for (txt, count, period) in [
+            (
+                "Activate its ability again. Works 1 time per game.",
+                1,
+                UsagePeriod::Game,
+            ),
+            (
+                "Gain +1 attack. Works 2 times per battle.",
+                2,
+                UsagePeriod::Battle,
+            ),
+            ("Gain +1 attack. Works once.", 1, UsagePeriod::Unspecified),
+            (
+                "Deal 1 damage to one random enemy. Triggers 3 times.",
+                3,
+                UsagePeriod::Trigger,
+            ),
+        ] {
+            let effect_txt = SAPText::new(txt);
+            let tokens = effect_txt.tokenize().unwrap();
+            let effects = Effect::new(None, &tokens).unwrap();
+
+            assert_eq!(effects[0].uses, Some(count));
+            assert_eq!(effects[0].usage, Some(UsageLimit { count, period }));
+        }
     }
 
     #[test]
@@ -554,6 +1622,7 @@ mod test {
                 cond_trigger: Some(EffectTrigger {
                     action: None,
                     number: None,
+                    count: TriggerCount::Once,
                     entity: Some(EntityType::Pet {
                         number: None,
                         name: None,
@@ -564,11 +1633,41 @@ mod test {
                     prim_pos: Some(PositionType::Trigger),
                     sec_pos: None
                 }),
+                condition: Some(Condition::Predicate(EffectTrigger {
+                    action: None,
+                    number: None,
+                    count: TriggerCount::Once,
+                    entity: Some(EntityType::Pet {
+                        number: None,
+                        name: None,
+                        attr: Some("Faint")
+                    }),
+                    target: None,
+                    logic: Some(LogicType::If),
+                    prim_pos: Some(PositionType::Trigger),
+                    sec_pos: None
+                })),
                 target: None,
+                target_count: None,
                 entities: vec![EntityType::Ability(None)],
+                amounts: vec![],
+                summon: None,
+                shop: None,
+                ability_ref: Some(AbilityRef {
+                    owner: AbilityOwner::Trigger,
+                    level: None,
+                    count: 1
+                }),
+                comparisons: vec![],
+                excluded: vec![],
                 position: vec![PositionType::Trigger],
                 action: Some(ActionType::Activate),
                 uses: Some(1),
+                usage: Some(UsageLimit {
+                    count: 1,
+                    period: UsagePeriod::Turn
+                }),
+                duration: Duration::Unspecified,
                 temp: false
             }
         )
@@ -590,6 +1689,7 @@ mod test {
                 cond_trigger: Some(EffectTrigger {
                     action: None,
                     number: None,
+                    count: TriggerCount::Once,
                     entity: Some(EntityType::Pet {
                         number: None,
                         name: None,
@@ -600,11 +1700,27 @@ mod test {
                     prim_pos: None,
                     sec_pos: None
                 }),
+                condition: None,
                 target: Some(TargetType::Enemy),
+                target_count: Some(1),
                 entities: vec![EntityType::Damage(Some(2))],
+                amounts: vec![Amount {
+                    stat: EntityType::Damage(None),
+                    value: Value::Times(
+                        Box::new(Value::Literal(2)),
+                        Box::new(Value::CountOf(effects[0].cond_trigger.clone().unwrap()))
+                    )
+                }],
+                summon: None,
+                shop: None,
+                ability_ref: None,
+                comparisons: vec![],
+                excluded: vec![],
                 position: vec![PositionType::Any],
                 action: Some(ActionType::Deal),
                 uses: None,
+                usage: None,
+                duration: Duration::Unspecified,
                 temp: false
             }
         );
@@ -619,17 +1735,43 @@ mod test {
                 cond_trigger: Some(EffectTrigger {
                     action: None,
                     number: Some(10),
+                    count: TriggerCount::Once,
                     entity: Some(EntityType::Gold(None)),
                     target: None,
                     logic: Some(LogicType::ForEach),
                     prim_pos: None,
                     sec_pos: None
                 }),
+                condition: None,
                 target: None,
+                target_count: None,
                 entities: vec![EntityType::Attack(Some(1)), EntityType::Health(Some(1))],
+                amounts: vec![
+                    Amount {
+                        stat: EntityType::Attack(None),
+                        value: Value::Times(
+                            Box::new(Value::Literal(1)),
+                            Box::new(Value::CountOf(effects[0].cond_trigger.clone().unwrap()))
+                        )
+                    },
+                    Amount {
+                        stat: EntityType::Health(None),
+                        value: Value::Times(
+                            Box::new(Value::Literal(1)),
+                            Box::new(Value::CountOf(effects[0].cond_trigger.clone().unwrap()))
+                        )
+                    }
+                ],
+                summon: None,
+                shop: None,
+                ability_ref: None,
+                comparisons: vec![],
+                excluded: vec![],
                 position: vec![PositionType::OnSelf],
                 action: Some(ActionType::Gain),
                 uses: None,
+                usage: None,
+                duration: Duration::EndOfBattle,
                 temp: true
             }
         )
@@ -647,7 +1789,9 @@ mod test {
             Effect {
                 trigger: None,
                 cond_trigger: None,
+                condition: None,
                 target: Some(TargetType::Enemy),
+                target_count: None,
                 entities: vec![
                     EntityType::Attack(Some(1)),
                     EntityType::Health(Some(1)),
@@ -657,9 +1801,38 @@ mod test {
                         attr: None
                     }
                 ],
+                amounts: vec![
+                    Amount {
+                        stat: EntityType::Attack(None),
+                        value: Value::Literal(1)
+                    },
+                    Amount {
+                        stat: EntityType::Health(None),
+                        value: Value::Literal(1)
+                    }
+                ],
+                summon: Some(SummonSpec {
+                    count: 1,
+                    pet: Some(EntityType::Pet {
+                        number: None,
+                        name: Some("Dirty Rat"),
+                        attr: None
+                    }),
+                    attack: Some(Value::Literal(1)),
+                    health: Some(Value::Literal(1)),
+                    level: None,
+                    placement: vec![PositionType::RightMost],
+                    inherits: vec![]
+                }),
+                shop: None,
+                ability_ref: None,
+                comparisons: vec![],
+                excluded: vec![],
                 position: vec![PositionType::RightMost],
                 action: Some(ActionType::Summon),
                 uses: None,
+                usage: None,
+                duration: Duration::Unspecified,
                 temp: false
             }
         )
@@ -687,11 +1860,28 @@ mod test {
                     ..Default::default()
                 }),
                 cond_trigger: None,
+                condition: None,
                 target: Some(TargetType::Enemy),
-                entities: vec![EntityType::AttackPercent(Some(100.0))],
+                target_count: None,
+                entities: vec![EntityType::AttackPercent(Some(Percent::new(100)))],
+                amounts: vec![Amount {
+                    stat: EntityType::Damage(None),
+                    value: Value::PercentOf {
+                        percent: Percent::new(100),
+                        stat: EntityType::Attack(None),
+                        holder: Operand::Position(PositionType::OnSelf)
+                    }
+                }],
+                summon: None,
+                shop: None,
+                ability_ref: None,
+                comparisons: vec![],
+                excluded: vec![],
                 position: vec![PositionType::Illest, PositionType::OnSelf],
                 action: Some(ActionType::Deal),
                 uses: None,
+                usage: None,
+                duration: Duration::Unspecified,
                 temp: false
             }
         )
@@ -703,4 +1893,749 @@ mod test {
         let tokens = effect_txt.tokenize().unwrap();
         assert!(Effect::new(None, &tokens).is_err())
     }
+
+    #[test]
+    fn test_interpret_one_of_entity_effect() {
+        let effect_txt = SAPText::new("Gain +1 attack or +1 health.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let group = EffectGroup::new(None, &tokens).unwrap();
+
+        assert_eq!(
+            group,
+            EffectGroup::OneOf(vec![
+                Effect {
+                    entities: vec![EntityType::Attack(Some(1))],
+                    amounts: vec![Amount {
+                        stat: EntityType::Attack(None),
+                        value: Value::Literal(1)
+                    }],
+                    position: vec![PositionType::OnSelf],
+                    action: Some(ActionType::Gain),
+                    ..Default::default()
+                },
+                Effect {
+                    entities: vec![EntityType::Health(Some(1))],
+                    amounts: vec![Amount {
+                        stat: EntityType::Health(None),
+                        value: Value::Literal(1)
+                    }],
+                    position: vec![PositionType::OnSelf],
+                    action: Some(ActionType::Gain),
+                    ..Default::default()
+                }
+            ])
+        )
+    }
+
+    #[test]
+    fn test_interpret_one_of_action_effect() {
+        let effect_txt =
+            SAPText::new("Gain +2 attack or give the friend behind +2 attack and +2 health.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let group = EffectGroup::new(None, &tokens).unwrap();
+
+        assert_eq!(
+            group,
+            EffectGroup::OneOf(vec![
+                Effect {
+                    entities: vec![EntityType::Attack(Some(2))],
+                    amounts: vec![Amount {
+                        stat: EntityType::Attack(None),
+                        value: Value::Literal(2)
+                    }],
+                    position: vec![PositionType::OnSelf],
+                    action: Some(ActionType::Gain),
+                    ..Default::default()
+                },
+                Effect {
+                    target: Some(TargetType::Friend),
+                    entities: vec![EntityType::Attack(Some(2)), EntityType::Health(Some(2))],
+                    amounts: vec![
+                        Amount {
+                            stat: EntityType::Attack(None),
+                            value: Value::Literal(2)
+                        },
+                        Amount {
+                            stat: EntityType::Health(None),
+                            value: Value::Literal(2)
+                        }
+                    ],
+                    position: vec![PositionType::Behind],
+                    action: Some(ActionType::Give),
+                    ..Default::default()
+                }
+            ])
+        )
+    }
+
+    #[test]
+    fn test_interpret_all_effect() {
+        let effect_txt =
+            SAPText::new("Gain +2 attack and give the friend behind +2 attack and +2 health.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let group = EffectGroup::new(None, &tokens).unwrap();
+
+        assert!(matches!(group, EffectGroup::All(_)));
+        assert_eq!(group.len(), 2);
+        // Flattened effects are the same.
+        assert_eq!(group.into_effects(), Effect::new(None, &tokens).unwrap());
+    }
+
+    #[test]
+    fn test_interpret_invalid_mixed_group_effect() {
+        let effect_txt =
+            SAPText::new("Gain +1 attack or +1 health and give the friend behind +1 health.");
+        let tokens = effect_txt.tokenize().unwrap();
+        assert!(EffectGroup::new(None, &tokens).is_err());
+        assert!(EffectGroup::new_with_spans(None, &tokens).is_err());
+
+        // Effects are still flattened.
+        let effects = Effect::new(None, &tokens).unwrap();
+        assert_eq!(effects.len(), 3);
+        assert_eq!(effects[2].action, Some(ActionType::Give));
+        assert_eq!(effects[2].position, vec![PositionType::Behind]);
+        assert_eq!(Effect::new_with_spans(None, &tokens).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_interpret_compound_and_condition() {
+        let effect_txt = SAPText::new(
+            "If this has a level 3 friend and is in battle, gain +1 attack and +2 health.",
+        );
+        let tokens = effect_txt.tokenize().unwrap();
+        let effects = Effect::new(None, &tokens).unwrap();
+
+        assert_eq!(
+            effects[0].condition,
+            Some(Condition::And(vec![
+                Condition::Predicate(EffectTrigger {
+                    entity: Some(EntityType::Level(Some(3))),
+                    target: Some(TargetType::Friend),
+                    logic: Some(LogicType::Have),
+                    ..Default::default()
+                }),
+                Condition::Predicate(EffectTrigger {
+                    entity: Some(EntityType::Battle(None)),
+                    logic: Some(LogicType::In),
+                    ..Default::default()
+                })
+            ]))
+        );
+        // Folded trigger keeps pieces of both predicates.
+        assert_eq!(
+            effects[0].cond_trigger,
+            Some(EffectTrigger {
+                entity: Some(EntityType::Battle(None)),
+                target: Some(TargetType::Friend),
+                logic: Some(LogicType::In),
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_interpret_compound_or_condition() {
+        let effect_txt = SAPText::new(
+            "If you have a toy or this has a level 3 friend and is in battle, gain +2 attack.",
+        );
+        let tokens = effect_txt.tokenize().unwrap();
+        let effects = Effect::new(None, &tokens).unwrap();
+
+        assert_eq!(
+            effects[0].condition,
+            Some(Condition::Or(vec![
+                Condition::Predicate(EffectTrigger {
+                    entity: Some(EntityType::Toy(None)),
+                    logic: Some(LogicType::Have),
+                    ..Default::default()
+                }),
+                Condition::And(vec![
+                    Condition::Predicate(EffectTrigger {
+                        entity: Some(EntityType::Level(Some(3))),
+                        target: Some(TargetType::Friend),
+                        logic: Some(LogicType::Have),
+                        ..Default::default()
+                    }),
+                    Condition::Predicate(EffectTrigger {
+                        entity: Some(EntityType::Battle(None)),
+                        logic: Some(LogicType::In),
+                        ..Default::default()
+                    })
+                ])
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_interpret_compare_condition() {
+        let effect_txt = SAPText::new("If the enemy has higher attack than this, gain +2 health.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let effects = Effect::new(None, &tokens).unwrap();
+
+        assert_eq!(
+            effects[0].condition,
+            Some(Condition::Compare(Comparison {
+                lhs: Operand::Target(TargetType::Enemy),
+                op: CompareOp::Greater,
+                stat: EntityType::Attack(None),
+                rhs: Operand::Position(PositionType::OnSelf)
+            }))
+        );
+
+        let effect_txt = SAPText::new("If it is tier 3 or lower, gain +2 health.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let effects = Effect::new(None, &tokens).unwrap();
+
+        assert_eq!(
+            effects[0].condition,
+            Some(Condition::Compare(Comparison {
+                lhs: Operand::Position(PositionType::Trigger),
+                op: CompareOp::LessEqual,
+                stat: EntityType::Tier(None),
+                rhs: Operand::Number(3)
+            }))
+        );
+    }
+
+    #[test]
+    fn test_interpret_compare_effect() {
+        let effect_txt = SAPText::new("Summon a lower tier pet.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let effects = Effect::new(None, &tokens).unwrap();
+
+        assert_eq!(
+            effects[0],
+            Effect {
+                entities: vec![EntityType::Pet {
+                    number: None,
+                    name: None,
+                    attr: None
+                }],
+                summon: Some(SummonSpec {
+                    count: 1,
+                    pet: Some(EntityType::Pet {
+                        number: None,
+                        name: None,
+                        attr: None
+                    }),
+                    attack: None,
+                    health: None,
+                    level: None,
+                    placement: vec![PositionType::OnSelf],
+                    inherits: vec![]
+                }),
+                shop: None,
+                ability_ref: None,
+                comparisons: vec![Comparison {
+                    lhs: Operand::Affected,
+                    op: CompareOp::Less,
+                    stat: EntityType::Tier(None),
+                    rhs: Operand::Position(PositionType::OnSelf)
+                }],
+                position: vec![PositionType::OnSelf],
+                action: Some(ActionType::Summon),
+                ..Default::default()
+            }
+        );
+
+        let effect_txt = SAPText::new("Summon one tier 3 or lower pet.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let effects = Effect::new(None, &tokens).unwrap();
+
+        assert_eq!(
+            effects[0].comparisons,
+            [Comparison {
+                lhs: Operand::Affected,
+                op: CompareOp::LessEqual,
+                stat: EntityType::Tier(None),
+                rhs: Operand::Number(3)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_interpret_equal_value_effect() {
+        let effect_txt = SAPText::new("Gain attack equal to 2x its health.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let effects = Effect::new(None, &tokens).unwrap();
+
+        assert_eq!(
+            effects[0].amounts,
+            [Amount {
+                stat: EntityType::Attack(None),
+                value: Value::Times(
+                    Box::new(Value::PercentOf {
+                        percent: Percent::new(100),
+                        stat: EntityType::Health(None),
+                        holder: Operand::Position(PositionType::Trigger)
+                    }),
+                    Box::new(Value::Literal(2))
+                )
+            }]
+        );
+
+        let effect_txt =
+            SAPText::new("Deal damage equal to 50% of its attack to one random enemy.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let effects = Effect::new(None, &tokens).unwrap();
+
+        assert_eq!(
+            effects[0].amounts,
+            [Amount {
+                stat: EntityType::Damage(None),
+                value: Value::PercentOf {
+                    percent: Percent::new(50),
+                    stat: EntityType::Attack(None),
+                    holder: Operand::Position(PositionType::Trigger)
+                }
+            }]
+        );
+        assert_eq!(effects[0].target, Some(TargetType::Enemy));
+    }
+
+    #[test]
+    fn test_interpret_sum_value_effect() {
+        let effect_txt = SAPText::new(
+            "Gain health equal to the sum of its attack and health and gain +1 attack.",
+        );
+        let tokens = effect_txt.tokenize().unwrap();
+        let effects = Effect::new(None, &tokens).unwrap();
+
+        assert_eq!(effects.len(), 2);
+        assert_eq!(
+            effects[0].amounts,
+            [Amount {
+                stat: EntityType::Health(None),
+                value: Value::Sum(vec![
+                    Value::PercentOf {
+                        percent: Percent::new(100),
+                        stat: EntityType::Attack(None),
+                        holder: Operand::Position(PositionType::Trigger)
+                    },
+                    Value::PercentOf {
+                        percent: Percent::new(100),
+                        stat: EntityType::Health(None),
+                        holder: Operand::Position(PositionType::Trigger)
+                    }
+                ])
+            }]
+        );
+    }
+
+    #[test]
+    fn test_interpret_unused_equal_value_effect() {
+        // Stat already has an amount so value is unused.
+        let effect_txt = SAPText::new("Gain +2 attack equal to its health.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let (effects, unused) = Effect::new_with_unused(None, &tokens).unwrap();
+
+        assert_eq!(
+            effects[0].amounts,
+            [Amount {
+                stat: EntityType::Attack(None),
+                value: Value::Literal(2)
+            }]
+        );
+        assert_eq!(
+            unused.iter().map(|token| token.text).collect::<Vec<&str>>(),
+            ["equal", "to", "its", "health"]
+        );
+        assert!(Effect::new_strict(None, &tokens).is_err());
+    }
+
+    #[test]
+    fn test_interpret_summon_spec_effect() {
+        let effect_txt = SAPText::new("Summon two 2/2 Rams at the back.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let effects = Effect::new(None, &tokens).unwrap();
+
+        assert_eq!(
+            effects[0].summon,
+            Some(SummonSpec {
+                count: 2,
+                pet: Some(EntityType::Pet {
+                    number: None,
+                    name: Some("Rams"),
+                    attr: None
+                }),
+                attack: Some(Value::Literal(2)),
+                health: Some(Value::Literal(2)),
+                level: None,
+                placement: vec![PositionType::LeftMost],
+                inherits: vec![]
+            })
+        );
+
+        let effect_txt = SAPText::new("Summon one tier 3 pet as a level 2 pet.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let effects = Effect::new(None, &tokens).unwrap();
+
+        assert_eq!(
+            effects[0].summon,
+            Some(SummonSpec {
+                count: 1,
+                pet: Some(EntityType::Pet {
+                    number: None,
+                    name: None,
+                    attr: None
+                }),
+                attack: None,
+                health: None,
+                level: Some(2),
+                placement: vec![PositionType::OnSelf],
+                inherits: vec![]
+            })
+        );
+
+        let effect_txt = SAPText::new("Resummon it as a 5/5 Bus with Chili.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let effects = Effect::new(None, &tokens).unwrap();
+
+        assert_eq!(
+            effects[0].summon,
+            Some(SummonSpec {
+                count: 1,
+                pet: Some(EntityType::Pet {
+                    number: None,
+                    name: Some("Bus"),
+                    attr: None
+                }),
+                attack: Some(Value::Literal(5)),
+                health: Some(Value::Literal(5)),
+                level: None,
+                placement: vec![PositionType::Trigger],
+                inherits: vec![EntityType::Perk {
+                    number: None,
+                    name: Some("Chili")
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn test_interpret_shop_effect() {
+        let effect_txt = SAPText::new("Discount shop food by 1 gold.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let effects = Effect::new(None, &tokens).unwrap();
+
+        assert_eq!(
+            effects[0].shop,
+            Some(ShopEffect {
+                slot: Some(ShopSlot::Food),
+                count: None,
+                price_change: Some(PriceChange::Discount(1)),
+                frozen: None,
+                item: None
+            })
+        );
+        assert_eq!(effects[0].target, Some(TargetType::Shop));
+
+        let effect_txt = SAPText::new("Freeze the left-most shop pet.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let effects = Effect::new(None, &tokens).unwrap();
+
+        assert_eq!(
+            effects[0].shop,
+            Some(ShopEffect {
+                slot: Some(ShopSlot::Pet),
+                count: None,
+                price_change: None,
+                frozen: Some(true),
+                item: None
+            })
+        );
+        assert_eq!(effects[0].position, [PositionType::LeftMost]);
+
+        let effect_txt = SAPText::new("Stock one free Melon Perk.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let effects = Effect::new(None, &tokens).unwrap();
+
+        assert_eq!(
+            effects[0].shop,
+            Some(ShopEffect {
+                slot: Some(ShopSlot::Food),
+                count: Some(1),
+                price_change: Some(PriceChange::Free),
+                frozen: None,
+                item: Some(EntityType::Perk {
+                    number: None,
+                    name: Some("Melon Perk")
+                })
+            })
+        );
+
+        let effect_txt = SAPText::new("Stock a free Apple.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let effects = Effect::new_strict(None, &tokens).unwrap();
+
+        assert_eq!(
+            effects[0].shop,
+            Some(ShopEffect {
+                slot: Some(ShopSlot::Food),
+                count: Some(1),
+                price_change: Some(PriceChange::Free),
+                frozen: None,
+                item: Some(EntityType::Food {
+                    number: None,
+                    name: Some("Apple")
+                })
+            })
+        );
+        // Free is not an amount of gold.
+        assert!(effects[0].amounts.is_empty());
+
+        // Plural foods are made singular.
+        let effect_txt = SAPText::new("Stock two free Sleeping Pills.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let effects = Effect::new_strict(None, &tokens).unwrap();
+
+        assert_eq!(
+            effects[0].shop,
+            Some(ShopEffect {
+                slot: Some(ShopSlot::Food),
+                count: Some(2),
+                price_change: Some(PriceChange::Free),
+                frozen: None,
+                item: Some(EntityType::Food {
+                    number: None,
+                    name: Some("Sleeping Pill")
+                })
+            })
+        );
+
+        let effect_txt = SAPText::new("Roll the shop.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let effects = Effect::new_strict(None, &tokens).unwrap();
+
+        assert_eq!(effects[0].action, Some(ActionType::Roll));
+        assert_eq!(effects[0].shop, Some(ShopEffect::default()));
+        assert_eq!(effects[0].target, Some(TargetType::Shop));
+
+        // Articles only count shop items.
+        let effect_txt = SAPText::new("Give a random friend +1 attack.");
+        let tokens = effect_txt.tokenize().unwrap();
+        assert!(Effect::new_strict(None, &tokens).is_ok());
+    }
+
+    #[test]
+    fn test_interpret_duration_effect() {
+        for (txt, duration) in [
+            ("Gain +1 attack until end of battle.", Duration::EndOfBattle),
+            ("Gain +1 attack until end of turn.", Duration::EndOfTurn),
+            ("Gain +1 attack this turn.", Duration::EndOfTurn),
+            ("Gain +1 attack next battle.", Duration::NextBattle),
+            ("Gain +1 attack for the next attack.", Duration::NextAttack),
+            ("Gain +1 attack permanently.", Duration::Permanent),
+            ("Gain +1 attack.", Duration::Unspecified),
+        ] {
+            let effect_txt = SAPText::new(txt);
+            let tokens = effect_txt.tokenize().unwrap();
+            let effects = Effect::new(None, &tokens).unwrap();
+
+            assert_eq!(effects[0].duration, duration, "{txt}");
+            assert_eq!(effects[0].temp(), duration.is_temp());
+            assert_eq!(effects[0].temp, duration.is_temp());
+            assert_eq!(effects[0].position, [PositionType::OnSelf]);
+        }
+    }
+
+    #[test]
+    fn test_interpret_unused_tokens() {
+        let effect_txt = SAPText::new("Gain two +1 attack.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let (effects, unused) = Effect::new_with_unused(None, &tokens).unwrap();
+        assert_eq!(effects.len(), 1);
+        assert_eq!(effects[0].target_count, None);
+        assert_eq!(
+            unused
+                .iter()
+                .map(|token| (&token.ttype, token.text))
+                .collect::<Vec<_>>(),
+            [(&TokenType::Numeric(NumericType::Number(Some(2))), "two")]
+        );
+        assert_eq!(
+            Effect::new_strict(None, &tokens).unwrap_err().to_string(),
+            "Unused tokens in effect: Numeric(Number(Some(2))) (two)"
+        );
+
+        // Count used by targets.
+        for (txt, target_count) in [
+            ("Give two random friends +1 attack.", Some(2)),
+            ("Deal 3 damage to three random enemies.", Some(3)),
+            ("Give a random friend +1 attack.", Some(1)),
+            ("Give the friend ahead +1 attack.", None),
+        ] {
+            let tokens = SAPText::new(txt).tokenize().unwrap();
+            let effects = Effect::new_strict(None, &tokens).unwrap();
+            assert_eq!(effects[0].target_count, target_count, "{txt}");
+        }
+
+        // Count used by summon.
+        let effect_txt = SAPText::new("Summon two 2/2 Rams.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let (_, unused) = Effect::new_with_unused(None, &tokens).unwrap();
+        assert!(unused.is_empty());
+        assert!(Effect::new_strict(None, &tokens).is_ok());
+
+        // Filler is unused but allowed in strict mode.
+        let effect_txt = SAPText::new("Give +1 attack to the friend ahead with Honey.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let (_, unused) = Effect::new_with_unused(None, &tokens).unwrap();
+        assert_eq!(
+            unused.iter().map(|token| token.text).collect::<Vec<&str>>(),
+            ["to", "with"]
+        );
+        assert!(Effect::new_strict(None, &tokens).is_ok());
+
+        let effect_txt = SAPText::new("Give the friend ahead +1 attack. This is permanent.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let (_, unused) = Effect::new_with_unused(None, &tokens).unwrap();
+        assert_eq!(
+            unused.iter().map(|token| token.text).collect::<Vec<&str>>(),
+            ["is"]
+        );
+
+        // Effects in sequence are separate effects.
+        let effect_txt = SAPText::new("Gain +1 attack, then give the friend ahead +1 health.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let effects = Effect::new_strict(None, &tokens).unwrap();
+        assert_eq!(effects.len(), 2);
+        assert_eq!(effects[0].action, Some(ActionType::Gain));
+        assert_eq!(effects[1].action, Some(ActionType::Give));
+
+        // Action that cannot start a new effect is not overwritten.
+        let effect_txt = SAPText::new("Gain give +1 attack.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let (effects, unused) = Effect::new_with_unused(None, &tokens).unwrap();
+        assert_eq!(effects[0].action, Some(ActionType::Gain));
+        assert_eq!(
+            unused.iter().map(|token| token.text).collect::<Vec<&str>>(),
+            ["give"]
+        );
+        assert!(Effect::new_strict(None, &tokens).is_err());
+
+        // Value tokens that are consumed but not used.
+        let effect_txt = SAPText::new("Gain attack equal to 50% of 25% of its health.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let (_, unused) = Effect::new_with_unused(None, &tokens).unwrap();
+        assert_eq!(
+            unused.iter().map(|token| token.text).collect::<Vec<&str>>(),
+            ["25"]
+        );
+        assert!(Effect::new_strict(None, &tokens).is_err());
+    }
+
+    #[test]
+    fn test_effect_hash_and_order() {
+        use std::collections::{BTreeSet, HashSet};
+
+        let txts = [
+            "Gain +1 attack.",
+            "Gain +1 attack.",
+            "Deal 50% attack damage to the lowest health enemy.",
+        ];
+        let tokens: Vec<_> = txts
+            .iter()
+            .map(|txt| SAPText::new(txt).tokenize().unwrap())
+            .collect();
+        let effects: Vec<Effect> = tokens
+            .iter()
+            .flat_map(|tokens| Effect::new(None, tokens).unwrap())
+            .collect();
+
+        let unique: HashSet<&Effect> = effects.iter().collect();
+        assert_eq!(unique.len(), 2);
+        let ordered: BTreeSet<&Effect> = effects.iter().collect();
+        assert_eq!(ordered.len(), 2);
+        assert!(unique.iter().all(|effect| ordered.contains(effect)));
+
+        let damage = &effects[2];
+        assert!(damage
+            .entities
+            .contains(&EntityType::AttackPercent(Some(Percent::new(50)))));
+    }
+
+    #[test]
+    fn test_interpret_negation() {
+        let effect_txt = SAPText::new("If this has no perk and is in battle, gain +1 attack.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let effects = Effect::new(None, &tokens).unwrap();
+
+        let Some(Condition::And(conds)) = &effects[0].condition else {
+            panic!("Expected joined condition.")
+        };
+        assert_eq!(
+            conds[0],
+            Condition::Not(Box::new(Condition::Predicate(EffectTrigger {
+                entity: Some(EntityType::Perk {
+                    number: None,
+                    name: None
+                }),
+                logic: Some(LogicType::Have),
+                ..Default::default()
+            })))
+        );
+        assert!(matches!(conds[1], Condition::Predicate(_)));
+
+        let effect_txt = SAPText::new("Give two random friends without a perk +1 health.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let effects = Effect::new(None, &tokens).unwrap();
+        assert_eq!(
+            effects[0].excluded,
+            [EntityType::Perk {
+                number: None,
+                name: None
+            }]
+        );
+        assert_eq!(effects[0].entities, [EntityType::Health(Some(1))]);
+
+        let effect_txt = SAPText::new("Give all non-Faint pets +1 attack.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let effects = Effect::new(None, &tokens).unwrap();
+        assert_eq!(
+            effects[0].excluded,
+            [EntityType::Pet {
+                number: None,
+                name: None,
+                attr: Some("Faint")
+            }]
+        );
+
+        let effect_txt = SAPText::new("If you didn't lose last battle, gain 1 gold.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let effects = Effect::new(None, &tokens).unwrap();
+        assert_eq!(
+            effects[0].condition,
+            Some(Condition::Not(Box::new(Condition::Predicate(
+                EffectTrigger {
+                    action: Some(ActionType::Lose),
+                    entity: Some(EntityType::Battle(None)),
+                    logic: Some(LogicType::If),
+                    ..Default::default()
+                }
+            ))))
+        );
+        assert_eq!(effects[0].action, Some(ActionType::Gain));
+        assert_eq!(effects[0].phase(), Some(Phase::Both));
+
+        // Negated entities cannot be counted so every friend isn't counted instead.
+        let effect_txt = SAPText::new("Gain +1 attack for each friend without a perk.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let err = Effect::new(None, &tokens).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Cannot count items without Perk"));
+
+        let effect_txt = SAPText::new("Gain +1 attack for each friend.");
+        let tokens = effect_txt.tokenize().unwrap();
+        let effects = Effect::new_strict(None, &tokens).unwrap();
+        assert_eq!(
+            effects[0].cond_trigger,
+            Some(EffectTrigger {
+                target: Some(TargetType::Friend),
+                logic: Some(LogicType::ForEach),
+                ..Default::default()
+            })
+        );
+    }
 }
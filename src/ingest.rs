@@ -0,0 +1,195 @@
+use serde::{Deserialize, Serialize};
+
+use crate::ability::Ability;
+
+/// Kind of item in a database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ItemKind {
+    /// Pet.
+    #[default]
+    Pet,
+    /// Food.
+    Food,
+    /// Toy.
+    Toy,
+}
+
+/// An item in a database.
+/// - ex. `{"name": "Ant", "tier": 1, "pack": "Turtle", "trigger": "Faint", "levels": [...]}`
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ItemEntry {
+    /// Item name.
+    pub name: String,
+    /// Kind of item.
+    #[serde(default)]
+    pub kind: ItemKind,
+    /// Item tier.
+    #[serde(default)]
+    pub tier: Option<usize>,
+    /// Pack item belongs to.
+    #[serde(default)]
+    pub pack: Option<String>,
+    /// Trigger text.
+    /// * Empty if no trigger.
+    #[serde(default)]
+    pub trigger: String,
+    /// Effect text at each level.
+    pub levels: Vec<String>,
+}
+
+/// Outcome of parsing an [`ItemEntry`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum IngestOutcome<'src> {
+    /// Entry parsed.
+    Ok {
+        /// One ability per trigger.
+        abilities: Vec<Ability<'src>>,
+        /// Warnings for abilities that may never occur.
+        warnings: Vec<String>,
+    },
+    /// Entry failed to parse.
+    Error {
+        /// Error message.
+        error: String,
+    },
+}
+
+/// Result of parsing an [`ItemEntry`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct IngestRecord<'src> {
+    /// Item name.
+    pub name: &'src str,
+    /// Kind of item.
+    pub kind: ItemKind,
+    /// Item tier.
+    pub tier: Option<usize>,
+    /// Pack item belongs to.
+    pub pack: Option<&'src str>,
+    /// Outcome of parsing item.
+    #[serde(flatten)]
+    pub outcome: IngestOutcome<'src>,
+}
+
+impl<'src> IngestRecord<'src> {
+    /// Check if entry parsed.
+    pub fn is_ok(&self) -> bool {
+        matches!(self.outcome, IngestOutcome::Ok { .. })
+    }
+}
+
+impl<'src> From<&'src ItemEntry> for IngestRecord<'src> {
+    fn from(entry: &'src ItemEntry) -> Self {
+        let levels: Vec<&str> = entry.levels.iter().map(String::as_str).collect();
+        let outcome = match Ability::from_triggers(&entry.trigger, &levels) {
+            Ok(abilities) => {
+                let warnings = abilities
+                    .iter()
+                    .flat_map(|ability| ability.phase_warnings())
+                    .map(|warning| warning.to_string())
+                    .collect();
+                IngestOutcome::Ok {
+                    abilities,
+                    warnings,
+                }
+            }
+            Err(err) => IngestOutcome::Error {
+                error: format!("{err:#}"),
+            },
+        };
+        IngestRecord {
+            name: &entry.name,
+            kind: entry.kind,
+            tier: entry.tier,
+            pack: entry.pack.as_deref(),
+            outcome,
+        }
+    }
+}
+
+/// Load items from a JSON array of [`ItemEntry`]s.
+///
+/// ### Params
+/// * `json`
+///     * JSON text.
+///
+/// ### Returns
+/// * [`ItemEntry`]s or error if JSON is invalid.
+pub fn load_entries(json: &str) -> anyhow::Result<Vec<ItemEntry>> {
+    Ok(serde_json::from_str(json)?)
+}
+
+/// Parse every item.
+/// * Items that fail to parse do not stop parsing. See [`IngestOutcome::Error`].
+///
+/// ### Params
+/// * `entries`
+///     * [`ItemEntry`]s
+///
+/// ### Returns
+/// * One [`IngestRecord`] per entry.
+///
+/// ```
+/// use saplex::ingest::{ingest, load_entries};
+///
+/// let entries = load_entries(r#"[
+///     {"name": "Ant", "tier": 1, "trigger": "Faint", "levels": [
+///         "Give one random friend +2 attack and +1 health.",
+///         "Give one random friend +4 attack and +2 health.",
+///         "Give one random friend +6 attack and +3 health."
+///     ]}
+/// ]"#).unwrap();
+/// let records = ingest(&entries);
+/// assert!(records[0].is_ok());
+///
+/// let output: String = serde_json::to_string(&records).unwrap();
+/// ```
+pub fn ingest(entries: &[ItemEntry]) -> Vec<IngestRecord<'_>> {
+    entries.iter().map(IngestRecord::from).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ingest, load_entries, IngestOutcome, ItemKind};
+
+    #[test]
+    fn test_ingest_entries() {
+        let entries = load_entries(
+            r#"[
+                {"name": "Pig", "tier": 1, "pack": "Turtle", "trigger": "Sell", "levels": [
+                    "Gain 1 gold.", "Gain 2 gold.", "Gain 3 gold."
+                ]},
+                {"name": "Broken", "kind": "toy", "trigger": "Sell", "levels": [
+                    "Gain 1 gold.", "Gain 2 trumpets.", "Gain 3 gold."
+                ]},
+                {"name": "Apple", "kind": "food", "levels": ["Gain +1 attack and +1 health."]}
+            ]"#,
+        )
+        .unwrap();
+        let records = ingest(&entries);
+
+        assert_eq!(records.len(), 3);
+        assert!(records[0].is_ok());
+        assert_eq!(
+            (records[0].name, records[0].tier, records[0].pack),
+            ("Pig", Some(1), Some("Turtle"))
+        );
+
+        assert_eq!(records[1].kind, ItemKind::Toy);
+        let IngestOutcome::Error { error } = &records[1].outcome else {
+            panic!("Expected error for divergent levels.")
+        };
+        assert!(error.starts_with("Level 2 differs in structure from level 1"));
+
+        assert_eq!(records[2].kind, ItemKind::Food);
+        let IngestOutcome::Ok { abilities, .. } = &records[2].outcome else {
+            panic!("Expected food with no trigger to parse.")
+        };
+        assert_eq!(abilities[0].trigger, None);
+
+        let output = serde_json::to_value(&records).unwrap();
+        assert_eq!(output[0]["status"], "ok");
+        assert_eq!(output[1]["status"], "error");
+    }
+}
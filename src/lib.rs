@@ -6,19 +6,56 @@
 #![deny(missing_docs)]
 #![deny(clippy::missing_docs_in_private_items)]
 
+/// SAP level-scaled abilities.
+pub mod ability;
+/// SAP comparison predicates.
+pub mod compare;
+/// SAP effect condition.
+pub mod condition;
+/// SAP corpus coverage.
+pub mod coverage;
+/// SAP effect diffs.
+pub mod diff;
+/// SAP effect duration.
+pub mod duration;
 /// SAP effect
 pub mod effect;
+/// SAP item database ingestion.
+#[cfg(feature = "serde")]
+pub mod ingest;
+/// SAP effect lints.
+pub mod lint;
+/// SAP effect normalization.
+pub mod normalize;
+/// SAP perks and ailments.
+pub mod perk;
+/// SAP game phases.
+pub mod phase;
+/// SAP ability references.
+pub mod reference;
 /// SAP text scanner state.
 pub mod scanner;
+/// SAP shop effects.
+pub mod shop;
+/// SAP effect source spans.
+pub mod span;
+/// SAP summon specification.
+pub mod summon;
 /// SAP token.
 pub mod token;
 /// SAP text tokenizer implementation.
 pub mod tokenize;
 /// SAP effect trigger.
 pub mod trigger;
+/// SAP effect usage limits.
+pub mod usage;
+/// SAP value expressions.
+pub mod value;
 
+pub use condition::Condition;
 #[doc = include_str!("../README.md")]
 pub use effect::Effect;
+pub use effect::EffectGroup;
 pub use token::{types::TokenType, Token};
 pub use tokenize::SAPText;
 pub use trigger::EffectTrigger;
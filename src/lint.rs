@@ -0,0 +1,562 @@
+use std::fmt::Display;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    effect::Effect,
+    scanner::Scanner,
+    span::EffectSpans,
+    token::{
+        actions::ActionType, logic::LogicType, position::PositionType, target::TargetType,
+        SAPTokens,
+    },
+    trigger::{EffectTrigger, TriggerKind},
+    usage::UsagePeriod,
+};
+
+/// Severity of a [`Diagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Severity {
+    /// Effect is likely wrong.
+    Warning,
+    /// Effect is invalid.
+    Error,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// Problem with an [`Effect`] found by a [`Rule`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Diagnostic {
+    /// Name of rule.
+    pub rule: String,
+    /// Severity of problem.
+    pub severity: Severity,
+    /// Description of problem.
+    pub message: String,
+    /// Span of source text with problem, if known.
+    pub span: Option<Scanner>,
+}
+
+impl Diagnostic {
+    /// Create a [`Severity::Warning`] diagnostic.
+    ///
+    /// ### Params
+    /// * `rule`
+    ///     * Name of rule. See [`Rule::name`].
+    /// * `message`
+    ///     * Description of problem.
+    /// * `span`
+    ///     * Span of source text with problem, if known.
+    pub fn warning(rule: &str, message: impl Into<String>, span: Option<&Scanner>) -> Self {
+        Diagnostic {
+            rule: rule.to_owned(),
+            severity: Severity::Warning,
+            message: message.into(),
+            span: span.cloned(),
+        }
+    }
+
+    /// Create a [`Severity::Error`] diagnostic.
+    /// * See [`Diagnostic::warning`].
+    pub fn error(rule: &str, message: impl Into<String>, span: Option<&Scanner>) -> Self {
+        Diagnostic {
+            severity: Severity::Error,
+            ..Diagnostic::warning(rule, message, span)
+        }
+    }
+
+    /// Check if diagnostic is an error.
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}[{}]: {}", self.severity, self.rule, self.message)?;
+        if let Some(span) = &self.span {
+            write!(f, " ({span})")?;
+        }
+        Ok(())
+    }
+}
+
+/// A check on a parsed [`Effect`].
+///
+/// ```
+/// use saplex::{
+///     lint::{Diagnostic, Linter, Rule},
+///     span::EffectSpans,
+///     token::ActionType,
+///     Effect, SAPText,
+/// };
+///
+/// /// House rule banning swaps.
+/// struct NoSwap;
+///
+/// impl Rule for NoSwap {
+///     fn name(&self) -> &str {
+///         "no-swap"
+///     }
+///
+///     fn check(&self, effect: &Effect, spans: &EffectSpans) -> Vec<Diagnostic> {
+///         if effect.action == Some(ActionType::Swap) {
+///             vec![Diagnostic::error(self.name(), "Swap is banned.", spans.action.as_ref())]
+///         } else {
+///             vec![]
+///         }
+///     }
+/// }
+///
+/// let mut linter = Linter::new();
+/// linter.add_rule(NoSwap);
+///
+/// let tokens = SAPText::new("Swap health with the friend ahead.").tokenize().unwrap();
+/// let diagnostics = linter.lint(None, &tokens).unwrap();
+/// assert_eq!(diagnostics[0].rule, "no-swap");
+/// ```
+pub trait Rule {
+    /// Name of rule.
+    /// - ex. `deal-without-damage`
+    fn name(&self) -> &str;
+
+    /// Check an effect.
+    ///
+    /// ### Params
+    /// * `effect`
+    ///     * [`Effect`] to check.
+    /// * `spans`
+    ///     * [`EffectSpans`] of `effect`.
+    ///
+    /// ### Returns
+    /// * [`Diagnostic`] for each problem found.
+    fn check(&self, effect: &Effect, spans: &EffectSpans) -> Vec<Diagnostic>;
+}
+
+/// [`ActionType::Gain`] only affects one pet, itself.
+/// * Trumpet effects may affect other positions.
+pub struct GainOnSelf;
+
+impl Rule for GainOnSelf {
+    fn name(&self) -> &str {
+        "gain-on-self"
+    }
+
+    fn check(&self, effect: &Effect, spans: &EffectSpans) -> Vec<Diagnostic> {
+        if effect.action != Some(ActionType::Gain) {
+            return vec![];
+        }
+        let position_span = spans.position.iter().flatten().next();
+        if effect.position.len() > 1 {
+            vec![Diagnostic::error(
+                self.name(),
+                format!("Only one pet can be affected by {:?}", effect.action),
+                position_span,
+            )]
+        } else if effect
+            .position
+            .first()
+            .is_some_and(|pos| *pos != PositionType::OnSelf)
+            && !effect.is_trumpet_effect()
+        {
+            vec![Diagnostic::error(
+                self.name(),
+                "Gain action only affects Self pet.",
+                position_span,
+            )]
+        } else {
+            vec![]
+        }
+    }
+}
+
+/// [`ActionType::Give`] must have a position.
+pub struct GiveHasPosition;
+
+impl Rule for GiveHasPosition {
+    fn name(&self) -> &str {
+        "give-has-position"
+    }
+
+    fn check(&self, effect: &Effect, spans: &EffectSpans) -> Vec<Diagnostic> {
+        if effect.action == Some(ActionType::Give) && effect.position.is_empty() {
+            vec![Diagnostic::error(
+                self.name(),
+                format!("Position must be given for {:?}", effect.action),
+                spans.action.as_ref(),
+            )]
+        } else {
+            vec![]
+        }
+    }
+}
+
+/// [`ActionType::Deal`] must have an amount of damage.
+/// - ex. `Deal damage to one random enemy.`
+pub struct DealWithoutDamage;
+
+impl Rule for DealWithoutDamage {
+    fn name(&self) -> &str {
+        "deal-without-damage"
+    }
+
+    fn check(&self, effect: &Effect, spans: &EffectSpans) -> Vec<Diagnostic> {
+        if effect.action == Some(ActionType::Deal) && effect.amounts.is_empty() {
+            vec![Diagnostic::error(
+                self.name(),
+                "Deal action has no amount of damage.",
+                spans.action.as_ref(),
+            )]
+        } else {
+            vec![]
+        }
+    }
+}
+
+/// [`LogicType::ForEach`] must count some entity.
+/// - ex. `Gain +1 attack for each friend.`
+pub struct ForEachWithoutEntity;
+
+impl Rule for ForEachWithoutEntity {
+    fn name(&self) -> &str {
+        "foreach-without-entity"
+    }
+
+    fn check(&self, effect: &Effect, spans: &EffectSpans) -> Vec<Diagnostic> {
+        match &effect.cond_trigger {
+            Some(EffectTrigger {
+                logic: Some(LogicType::ForEach),
+                entity: None,
+                ..
+            }) => vec![Diagnostic::error(
+                self.name(),
+                "For each has no entity to count.",
+                spans.cond_trigger.as_ref(),
+            )],
+            _ => vec![],
+        }
+    }
+}
+
+/// [`ActionType::Give`] to enemies is usually a mistake.
+/// - ex. `Give one random enemy +1 attack.`
+pub struct GiveToEnemy;
+
+impl Rule for GiveToEnemy {
+    fn name(&self) -> &str {
+        "give-to-enemy"
+    }
+
+    fn check(&self, effect: &Effect, spans: &EffectSpans) -> Vec<Diagnostic> {
+        if effect.action == Some(ActionType::Give) && effect.target == Some(TargetType::Enemy) {
+            vec![Diagnostic::warning(
+                self.name(),
+                "Give action targets enemies.",
+                spans.target.as_ref(),
+            )]
+        } else {
+            vec![]
+        }
+    }
+}
+
+/// Positions that cannot both select the same pet.
+/// - ex. `Give the strongest and weakest friend +1 attack.`
+pub struct ContradictingPositions;
+
+impl ContradictingPositions {
+    /// Pairs of contradicting positions.
+    const PAIRS: [(PositionType, PositionType); 5] = [
+        (PositionType::OnSelf, PositionType::NonSelf),
+        (PositionType::All, PositionType::Any),
+        (PositionType::Highest, PositionType::Lowest),
+        (PositionType::Strongest, PositionType::Weakest),
+        (PositionType::Healthiest, PositionType::Illest),
+    ];
+}
+
+impl Rule for ContradictingPositions {
+    fn name(&self) -> &str {
+        "contradicting-positions"
+    }
+
+    fn check(&self, effect: &Effect, spans: &EffectSpans) -> Vec<Diagnostic> {
+        let position_span = |pos: &PositionType| {
+            effect
+                .position
+                .iter()
+                .position(|effect_pos| effect_pos == pos)
+                .and_then(|idx| spans.position.get(idx).cloned().flatten())
+        };
+        ContradictingPositions::PAIRS
+            .iter()
+            .filter(|(pos, other_pos)| {
+                effect.position.contains(pos) && effect.position.contains(other_pos)
+            })
+            .map(|(pos, other_pos)| {
+                let span = match (position_span(pos), position_span(other_pos)) {
+                    (Some(span), Some(other_span)) => Some(span.join(&other_span)),
+                    (span, other_span) => span.or(other_span),
+                };
+                Diagnostic::error(
+                    self.name(),
+                    format!("Positions {pos:?} and {other_pos:?} contradict."),
+                    span.as_ref(),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Limited uses of an effect whose trigger can only occur once.
+/// - ex. `Faint` -> `Gain +1 attack. Works 1 time per turn.`
+pub struct UsesOnOneShotTrigger;
+
+impl UsesOnOneShotTrigger {
+    /// Triggers occurring at most once per battle or item.
+    const ONE_SHOT: [TriggerKind; 6] = [
+        TriggerKind::StartOfBattle,
+        TriggerKind::Faint,
+        TriggerKind::BeforeFaint,
+        TriggerKind::Summoned,
+        TriggerKind::Sell,
+        TriggerKind::Buy,
+    ];
+}
+
+impl Rule for UsesOnOneShotTrigger {
+    fn name(&self) -> &str {
+        "uses-on-one-shot-trigger"
+    }
+
+    fn check(&self, effect: &Effect, spans: &EffectSpans) -> Vec<Diagnostic> {
+        let kind = effect
+            .trigger
+            .as_ref()
+            .and_then(|trigger| trigger.kind().ok())
+            .filter(|kind| UsesOnOneShotTrigger::ONE_SHOT.contains(kind));
+        let is_limited = effect
+            .usage
+            .as_ref()
+            .is_some_and(|usage| usage.period != UsagePeriod::Trigger);
+        match kind {
+            Some(kind) if is_limited => vec![Diagnostic::warning(
+                self.name(),
+                format!("Uses are limited but {kind:?} trigger only occurs once."),
+                spans.uses.as_ref(),
+            )],
+            _ => vec![],
+        }
+    }
+}
+
+/// Registry of [`Rule`]s checked against effects.
+pub struct Linter {
+    /// Rules to check.
+    rules: Vec<Box<dyn Rule>>,
+}
+
+impl Default for Linter {
+    fn default() -> Self {
+        Linter::new()
+    }
+}
+
+impl Linter {
+    /// Create a linter with all built-in rules.
+    pub fn new() -> Self {
+        let mut linter = Linter::empty();
+        linter
+            .add_rule(GainOnSelf)
+            .add_rule(GiveHasPosition)
+            .add_rule(DealWithoutDamage)
+            .add_rule(ForEachWithoutEntity)
+            .add_rule(GiveToEnemy)
+            .add_rule(ContradictingPositions)
+            .add_rule(UsesOnOneShotTrigger);
+        linter
+    }
+
+    /// Create a linter with no rules.
+    pub fn empty() -> Self {
+        Linter { rules: vec![] }
+    }
+
+    /// Add a rule.
+    ///
+    /// ### Returns
+    /// * Instance
+    pub fn add_rule(&mut self, rule: impl Rule + 'static) -> &mut Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Remove all rules with a name.
+    ///
+    /// ### Returns
+    /// * `true` if any rule was removed.
+    pub fn remove_rule(&mut self, name: &str) -> bool {
+        let n_rules = self.rules.len();
+        self.rules.retain(|rule| rule.name() != name);
+        self.rules.len() != n_rules
+    }
+
+    /// Names of rules.
+    pub fn rule_names(&self) -> impl Iterator<Item = &str> {
+        self.rules.iter().map(|rule| rule.name())
+    }
+
+    /// Check an effect against all rules.
+    ///
+    /// ### Params
+    /// * `effect`
+    ///     * [`Effect`] to check.
+    /// * `spans`
+    ///     * [`EffectSpans`] of `effect`.
+    ///
+    /// ### Returns
+    /// * [`Diagnostic`]s of all rules.
+    pub fn check(&self, effect: &Effect, spans: &EffectSpans) -> Vec<Diagnostic> {
+        self.rules
+            .iter()
+            .flat_map(|rule| rule.check(effect, spans))
+            .collect()
+    }
+
+    /// Parse effect text and check all of its effects.
+    ///
+    /// ### Params
+    /// * `trigger`
+    ///     * Optional [`EffectTrigger`]
+    /// * `tokens`
+    ///     * Effect text tokens as [`SAPTokens`].
+    ///
+    /// ### Returns
+    /// * [`Diagnostic`]s of all effects.
+    /// * Errors if effect text cannot be parsed.
+    pub fn lint<'src>(
+        &self,
+        trigger: Option<EffectTrigger<'src>>,
+        tokens: &SAPTokens<'src>,
+    ) -> anyhow::Result<Vec<Diagnostic>> {
+        Ok(Effect::new_with_spans(trigger, tokens)?
+            .iter()
+            .flat_map(|(effect, spans)| self.check(effect, spans))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{EffectTrigger, SAPText};
+
+    use super::{Diagnostic, Linter, Severity};
+
+    /// Lint effect text with trigger text.
+    fn lint(trigger: &str, effect: &str) -> Vec<Diagnostic> {
+        let trigger = (!trigger.is_empty()).then(|| {
+            let mut triggers: Vec<EffectTrigger> = SAPText::new(trigger)
+                .tokenize()
+                .unwrap()
+                .try_into()
+                .unwrap();
+            triggers.remove(0)
+        });
+        let tokens = SAPText::new(effect).tokenize().unwrap();
+        Linter::new().lint(trigger, &tokens).unwrap()
+    }
+
+    #[test]
+    fn test_lint_builtin_rules() {
+        for (trigger, effect, rule, severity) in [
+            (
+                "",
+                "Deal damage to one random enemy.",
+                "deal-without-damage",
+                Severity::Error,
+            ),
+            (
+                "",
+                "Gain +1 attack for each friend.",
+                "foreach-without-entity",
+                Severity::Error,
+            ),
+            (
+                "",
+                "Give one random enemy +1 attack.",
+                "give-to-enemy",
+                Severity::Warning,
+            ),
+            (
+                "",
+                "Give the strongest and weakest friend +1 attack.",
+                "contradicting-positions",
+                Severity::Error,
+            ),
+            (
+                "Faint",
+                "Gain +1 attack. Works 1 time per turn.",
+                "uses-on-one-shot-trigger",
+                Severity::Warning,
+            ),
+            (
+                "",
+                "Gain +1 attack to the friend ahead.",
+                "gain-on-self",
+                Severity::Error,
+            ),
+            ("", "Give +1 attack.", "give-has-position", Severity::Error),
+        ] {
+            let diagnostics = lint(trigger, effect);
+            assert_eq!(diagnostics.len(), 1, "{effect} {diagnostics:?}");
+            assert_eq!(diagnostics[0].rule, rule);
+            assert_eq!(diagnostics[0].severity, severity);
+            assert!(diagnostics[0].span.is_some(), "{effect}");
+        }
+
+        assert_eq!(lint("Faint", "Deal 2 damage to one random enemy."), []);
+        assert_eq!(
+            lint("End turn", "Gain +1 attack. Works 1 time per turn."),
+            []
+        );
+    }
+
+    #[test]
+    fn test_lint_remove_rule() {
+        let mut linter = Linter::new();
+        assert!(linter.remove_rule("give-to-enemy"));
+        assert!(!linter.remove_rule("give-to-enemy"));
+        assert!(!linter.rule_names().any(|name| name == "give-to-enemy"));
+
+        let tokens = SAPText::new("Give one random enemy +1 attack.")
+            .tokenize()
+            .unwrap();
+        assert_eq!(linter.lint(None, &tokens).unwrap(), []);
+
+        assert!(linter.remove_rule("gain-on-self"));
+        let tokens = SAPText::new("Gain +1 attack to the friend ahead.")
+            .tokenize()
+            .unwrap();
+        assert_eq!(linter.lint(None, &tokens).unwrap(), []);
+    }
+
+    #[test]
+    fn test_diagnostic_display() {
+        let diagnostics = lint("", "Give one random enemy +1 attack.");
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "warning[give-to-enemy]: Give action targets enemies. (Line 1 (16-21))"
+        );
+    }
+}
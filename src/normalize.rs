@@ -0,0 +1,282 @@
+use std::cmp::Ordering;
+
+use crate::{
+    ability::Ability,
+    condition::Condition,
+    effect::Effect,
+    token::{actions::ActionType, attribute::EntityType, position::PositionType},
+    trigger::EffectTrigger,
+};
+
+/// Remove duplicates from items in place, keeping the first of each.
+fn dedup_unsorted<T: PartialEq>(items: &mut Vec<T>) {
+    let mut unique: Vec<T> = Vec::with_capacity(items.len());
+    for item in items.drain(..) {
+        if !unique.contains(&item) {
+            unique.push(item)
+        }
+    }
+    *items = unique;
+}
+
+/// Order entities by kind, ignoring their values.
+/// * Values are ignored so that effects at each level of an [`Ability`] are ordered the same.
+fn cmp_entity_kind(entity: &EntityType, other: &EntityType) -> Ordering {
+    entity.without_value().cmp(&other.without_value())
+}
+
+/// Collapse synonymous positions.
+/// * `nearest friend ahead` -> `friend ahead`
+fn collapse_positions(positions: &mut Vec<PositionType>) {
+    if positions
+        .iter()
+        .any(|pos| matches!(pos, PositionType::Ahead | PositionType::Behind))
+    {
+        positions.retain(|pos| *pos != PositionType::Nearest)
+    }
+}
+
+impl<'src> EffectTrigger<'src> {
+    /// Normalize trigger to a canonical form.
+    /// * Synonymous positions are collapsed.
+    ///     * ex. `Nearest friend ahead attacks` -> `Friend ahead attacks`
+    /// * Explicit self is made implicit.
+    ///     * ex. `This hurt` -> `Hurt`
+    /// * Duplicate positions are removed.
+    pub fn normalize(&mut self) {
+        let mut positions: Vec<PositionType> = [self.prim_pos, self.sec_pos]
+            .into_iter()
+            .flatten()
+            .collect();
+        collapse_positions(&mut positions);
+        dedup_unsorted(&mut positions);
+        if self.target.is_none() && positions == [PositionType::OnSelf] {
+            positions.clear()
+        }
+        let mut positions = positions.into_iter();
+        self.prim_pos = positions.next();
+        self.sec_pos = positions.next();
+    }
+}
+
+impl<'src> Condition<'src> {
+    /// Normalize condition to a canonical form.
+    /// * Predicates are normalized. See [`EffectTrigger::normalize`].
+    /// * Nested conditions of the same kind are flattened and duplicates removed.
+    ///     * ex. `a and (b and a)` -> `a and b`
+    /// * Double negation is removed.
+    pub fn normalize(&mut self) {
+        match self {
+            Condition::Predicate(pred) => pred.normalize(),
+            Condition::Compare(_) => {}
+            Condition::And(conds) => flatten_conditions(conds, true),
+            Condition::Or(conds) => flatten_conditions(conds, false),
+            Condition::Not(cond) => cond.normalize(),
+        }
+        // Unwrap single conditions and double negation.
+        match self {
+            Condition::And(conds) | Condition::Or(conds) if conds.len() == 1 => {
+                let cond = conds.remove(0);
+                *self = cond
+            }
+            Condition::Not(cond) => {
+                if let Condition::Not(inner) = cond.as_mut() {
+                    let inner = std::mem::replace(inner.as_mut(), Condition::And(vec![]));
+                    *self = inner
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Normalize conditions, flattening nested conditions of the same kind and removing duplicates.
+///
+/// ### Params
+/// * `conds`
+///     * Conditions of a [`Condition::And`] or [`Condition::Or`].
+/// * `is_and`
+///     * If `true`, `conds` are of a [`Condition::And`].
+fn flatten_conditions(conds: &mut Vec<Condition>, is_and: bool) {
+    let mut flat_conds = vec![];
+    for mut cond in conds.drain(..) {
+        cond.normalize();
+        match cond {
+            Condition::And(inner) if is_and => flat_conds.extend(inner),
+            Condition::Or(inner) if !is_and => flat_conds.extend(inner),
+            cond => flat_conds.push(cond),
+        }
+    }
+    dedup_unsorted(&mut flat_conds);
+    *conds = flat_conds;
+}
+
+impl<'src> Effect<'src> {
+    /// Normalize effect to a canonical form so semantically equal effects compare equal.
+    /// * Triggers and condition are normalized. See [`EffectTrigger::normalize`] and [`Condition::normalize`].
+    /// * Implicit self position is filled for [`ActionType::Gain`], [`ActionType::Summon`], and [`ActionType::Take`].
+    /// * Synonymous positions are collapsed.
+    ///     * ex. `nearest friend ahead` -> `friend ahead`
+    /// * Positions, entities, amounts, and excluded entities are sorted and deduplicated.
+    /// * Deprecated [`Effect::uses`] matches [`Effect::usage`].
+    ///
+    /// Source spans are not updated. See [`EffectSpans`](crate::span::EffectSpans).
+    ///
+    /// ```
+    /// use saplex::{SAPText, Effect};
+    ///
+    /// fn parse(txt: &str) -> Vec<Effect<'_>> {
+    ///     let tokens = SAPText::new(txt).tokenize().unwrap();
+    ///     let mut effects = Effect::new(None, &tokens).unwrap();
+    ///     effects.iter_mut().for_each(Effect::normalize);
+    ///     effects
+    /// }
+    /// assert_eq!(
+    ///     parse("Give the nearest friend ahead +1 health and +1 attack."),
+    ///     parse("Give the friend ahead +1 attack and +1 health.")
+    /// );
+    /// ```
+    pub fn normalize(&mut self) {
+        if let Some(trigger) = self.trigger.as_mut() {
+            trigger.normalize()
+        }
+        if let Some(cond_trigger) = self.cond_trigger.as_mut() {
+            cond_trigger.normalize()
+        }
+        if let Some(condition) = self.condition.as_mut() {
+            condition.normalize()
+        }
+
+        let is_implicit_self = match self.action {
+            Some(ActionType::Gain) => !self.is_trumpet_effect(),
+            Some(ActionType::Summon) => true,
+            Some(ActionType::Take) => self.target.is_none(),
+            _ => false,
+        };
+        if self.position.is_empty() && is_implicit_self {
+            self.position.push(PositionType::OnSelf)
+        }
+        collapse_positions(&mut self.position);
+        self.position.sort();
+        self.position.dedup();
+
+        self.entities.sort_by(cmp_entity_kind);
+        self.entities.dedup();
+        self.amounts
+            .sort_by(|amount, other| cmp_entity_kind(&amount.stat, &other.stat));
+        self.amounts.dedup();
+        dedup_unsorted(&mut self.comparisons);
+        self.excluded.sort_by(cmp_entity_kind);
+        self.excluded.dedup();
+
+        #[allow(deprecated)]
+        {
+            self.uses = self.usage.map(|usage| usage.count);
+        }
+    }
+}
+
+impl<'src> Ability<'src> {
+    /// Normalize ability to a canonical form.
+    /// * Effects at every level are normalized. See [`Effect::normalize`].
+    ///
+    /// ### Returns
+    /// * Error if normalized levels differ in structure.
+    pub fn normalize(&mut self) -> anyhow::Result<()> {
+        if let Some(trigger) = self.trigger.as_mut() {
+            trigger.normalize()
+        }
+        let level_effects: Vec<Vec<Effect<'src>>> = (1..=self.levels())
+            .filter_map(|level| self.at_level(level))
+            .map(|mut effects| {
+                for effect in effects.iter_mut() {
+                    effect.trigger.clone_from(&self.trigger);
+                    effect.normalize()
+                }
+                effects
+            })
+            .collect();
+        *self =
+            Ability::from_level_effects(self.trigger.clone(), level_effects.into_iter().map(Ok))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ability::Ability,
+        condition::Condition,
+        token::{logic::LogicType, position::PositionType},
+        EffectTrigger, SAPText,
+    };
+
+    #[test]
+    fn test_normalize_trigger() {
+        fn parse(txt: &str) -> Vec<EffectTrigger<'_>> {
+            let mut triggers: Vec<EffectTrigger> =
+                SAPText::new(txt).tokenize().unwrap().try_into().unwrap();
+            triggers.iter_mut().for_each(EffectTrigger::normalize);
+            triggers
+        }
+        assert_eq!(parse("This hurt"), parse("Hurt"));
+        assert_eq!(
+            parse("Nearest friend ahead attacks"),
+            parse("Friend ahead attacks")
+        );
+        assert_eq!(
+            parse("Friend ahead attacks")[0].prim_pos,
+            Some(PositionType::Ahead)
+        );
+    }
+
+    #[test]
+    fn test_normalize_condition() {
+        let pred = |logic| {
+            Condition::Predicate(EffectTrigger {
+                logic: Some(logic),
+                ..Default::default()
+            })
+        };
+        let mut cond = Condition::And(vec![
+            pred(LogicType::In),
+            Condition::And(vec![pred(LogicType::Have), pred(LogicType::In)]),
+            pred(LogicType::Outside).negate().negate(),
+        ]);
+        cond.normalize();
+        assert_eq!(
+            cond,
+            Condition::And(vec![
+                pred(LogicType::In),
+                pred(LogicType::Have),
+                pred(LogicType::Outside)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_normalize_ability() {
+        let mut ability = Ability::new(
+            "This hurt",
+            &[
+                "Give the nearest friend ahead +1 health and +1 attack.",
+                "Give the nearest friend ahead +2 health and +3 attack.",
+            ],
+        )
+        .unwrap();
+        let mut other_ability = Ability::new(
+            "Hurt",
+            &[
+                "Give the friend ahead +1 attack and +1 health.",
+                "Give the friend ahead +3 attack and +2 health.",
+            ],
+        )
+        .unwrap();
+        assert_ne!(ability, other_ability);
+
+        ability.normalize().unwrap();
+        other_ability.normalize().unwrap();
+        assert_eq!(ability, other_ability);
+        assert_eq!(ability.values, other_ability.values);
+    }
+}
@@ -0,0 +1,127 @@
+use anyhow::bail;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    effect::Effect,
+    token::{actions::ActionType, attribute::EntityType, position::PositionType, SAPTokens},
+    value::Value,
+};
+
+/// A perk or ailment and its passive effects on the pet holding it.
+/// - ex. `Garlic`: `Take 2 less damage.`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HeldItem<'src> {
+    /// Perk or ailment.
+    /// * Always a [`EntityType::Perk`] or [`EntityType::Ailment`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub item: EntityType<'src>,
+    /// Effects of item on its holder.
+    /// * The holder is [`PositionType::OnSelf`].
+    pub effects: Vec<Effect<'src>>,
+}
+
+impl<'src> HeldItem<'src> {
+    /// Create a held item from its description.
+    ///
+    /// ### Params
+    /// * `item`
+    ///     * [`EntityType::Perk`] or [`EntityType::Ailment`].
+    /// * `tokens`
+    ///     * Tokens of item description.
+    ///
+    /// ### Returns
+    /// * [`HeldItem`] or error if not a perk or ailment or its description is invalid.
+    ///
+    /// ```
+    /// use saplex::{SAPText, perk::HeldItem, token::EntityType};
+    ///
+    /// let tokens = SAPText::new("Take 2 less damage.").tokenize().unwrap();
+    /// let garlic = EntityType::Perk { number: None, name: Some("Garlic") };
+    /// let held_item = HeldItem::new(garlic, &tokens).unwrap();
+    /// assert_eq!(held_item.damage_modifier(), Some(-2));
+    /// ```
+    pub fn new(item: EntityType<'src>, tokens: &SAPTokens<'src>) -> anyhow::Result<Self> {
+        if !matches!(item, EntityType::Perk { .. } | EntityType::Ailment { .. }) {
+            bail!("{item:?} is not a perk or ailment.")
+        }
+        Ok(HeldItem {
+            item,
+            effects: Effect::new(None, tokens)?,
+        })
+    }
+
+    /// Change in damage taken by the holder.
+    /// * See [`Effect::damage_modifier`].
+    ///
+    /// ### Returns
+    /// * Sum of damage modifiers or [`None`] if no effect modifies damage.
+    pub fn damage_modifier(&self) -> Option<i32> {
+        self.effects
+            .iter()
+            .filter_map(Effect::damage_modifier)
+            .reduce(|total, modifier| total + modifier)
+    }
+}
+
+impl<'src> Effect<'src> {
+    /// Change in damage taken by this pet.
+    /// * Negative values reduce damage taken.
+    /// - ex. `Take 2 less damage.` -> `-2`
+    /// - ex. `Take 3 extra damage.` -> `3`
+    ///
+    /// ### Returns
+    /// * Change in damage or [`None`] if not a damage modifier.
+    pub fn damage_modifier(&self) -> Option<i32> {
+        if self.action != Some(ActionType::Take) || self.position != [PositionType::OnSelf] {
+            return None;
+        }
+        self.amounts.iter().find_map(|amount| match amount.value {
+            Value::Literal(value) if amount.stat == EntityType::Damage(None) => Some(value),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::HeldItem;
+    use crate::{token::attribute::EntityType, usage::UsagePeriod, SAPText};
+
+    #[test]
+    fn test_held_item_damage_modifier() {
+        let tokens = SAPText::new("Take 20 less damage. Works once per battle.")
+            .tokenize()
+            .unwrap();
+        let melon = EntityType::Perk {
+            number: None,
+            name: Some("Melon"),
+        };
+        let held_item = HeldItem::new(melon, &tokens).unwrap();
+        assert_eq!(held_item.damage_modifier(), Some(-20));
+        assert_eq!(
+            held_item.effects[0].usage.map(|usage| usage.period),
+            Some(UsagePeriod::Battle)
+        );
+
+        let tokens = SAPText::new("Take 3 extra damage.").tokenize().unwrap();
+        let weak = EntityType::Ailment {
+            number: None,
+            name: Some("Weak"),
+        };
+        assert_eq!(
+            HeldItem::new(weak, &tokens).unwrap().damage_modifier(),
+            Some(3)
+        );
+
+        let tokens = SAPText::new("Gain +1 attack.").tokenize().unwrap();
+        let pet = EntityType::Pet {
+            number: None,
+            name: Some("Ant"),
+            attr: None,
+        };
+        assert!(HeldItem::new(pet, &tokens).is_err());
+    }
+}
@@ -0,0 +1,299 @@
+use std::fmt::Display;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    condition::Condition,
+    effect::Effect,
+    token::{actions::ActionType, attribute::EntityType, logic::LogicType, target::TargetType},
+    trigger::EffectTrigger,
+};
+
+/// Game phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Phase {
+    /// Shop phase.
+    /// - ex. `Sell`
+    Shop,
+    /// Battle phase.
+    /// - ex. `Before attack`
+    Battle,
+    /// Either phase.
+    /// - ex. `Faint`
+    #[default]
+    Both,
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Shop => write!(f, "shop"),
+            Phase::Battle => write!(f, "battle"),
+            Phase::Both => write!(f, "shop or battle"),
+        }
+    }
+}
+
+impl Phase {
+    /// Phases in both this and another phase.
+    ///
+    /// ### Returns
+    /// * [`Phase`] or [`None`] if no phase in common.
+    pub fn intersect(self, other: Phase) -> Option<Phase> {
+        match (self, other) {
+            (Phase::Both, phase) | (phase, Phase::Both) => Some(phase),
+            (phase, other_phase) if phase == other_phase => Some(phase),
+            _ => None,
+        }
+    }
+
+    /// Phases in either this or another phase.
+    pub fn union(self, other: Phase) -> Phase {
+        if self == other {
+            self
+        } else {
+            Phase::Both
+        }
+    }
+
+    /// Phases not in this phase.
+    ///
+    /// ### Returns
+    /// * [`Phase`] or [`None`] if all phases.
+    pub fn complement(self) -> Option<Phase> {
+        match self {
+            Phase::Shop => Some(Phase::Battle),
+            Phase::Battle => Some(Phase::Shop),
+            Phase::Both => None,
+        }
+    }
+
+    /// Get phase of an [`EffectTrigger`].
+    /// * ex. `Start of battle` -> [`Phase::Battle`]
+    /// * ex. `Sell` -> [`Phase::Shop`]
+    /// * ex. `Faint` -> [`Phase::Both`]
+    pub fn from_trigger(trigger: &EffectTrigger) -> Phase {
+        if trigger.target == Some(TargetType::Shop)
+            || trigger
+                .action
+                .is_some_and(|action| action.is_shop_related())
+        {
+            return Phase::Shop;
+        }
+        match (trigger.logic, &trigger.entity, trigger.action) {
+            // ex. Outside of battle
+            (Some(LogicType::Outside), Some(EntityType::Battle(_)), _) => Phase::Shop,
+            // Outcome of a battle is known in either phase.
+            // ex. If you lost last battle
+            (_, Some(EntityType::Battle(_)), Some(ActionType::Win | ActionType::Lose)) => {
+                Phase::Both
+            }
+            // ex. In battle
+            // ex. Start of battle
+            (Some(_), Some(EntityType::Battle(_)), _) => Phase::Battle,
+            // ex. Start of turn
+            // ex. End turn
+            (Some(LogicType::Start | LogicType::End), Some(EntityType::Turn(_)), _) => Phase::Shop,
+            // ex. Before attack
+            (Some(LogicType::Before | LogicType::After), Some(EntityType::Attack(_)), _) => {
+                Phase::Battle
+            }
+            (_, _, Some(action)) if action.is_battle_related() => Phase::Battle,
+            _ => Phase::Both,
+        }
+    }
+
+    /// Get phase of a [`Condition`].
+    ///
+    /// ### Returns
+    /// * [`Phase`] or [`None`] if condition can never be met.
+    pub fn from_condition(condition: &Condition) -> Option<Phase> {
+        match condition {
+            Condition::Predicate(pred) => Some(Phase::from_trigger(pred)),
+            Condition::Compare(_) => Some(Phase::Both),
+            Condition::And(conds) => conds.iter().try_fold(Phase::Both, |phase, cond| {
+                phase.intersect(Phase::from_condition(cond)?)
+            }),
+            Condition::Or(conds) => conds
+                .iter()
+                .filter_map(Phase::from_condition)
+                .reduce(Phase::union),
+            Condition::Not(cond) => match Phase::from_condition(cond) {
+                Some(phase) => Some(phase.complement().unwrap_or(Phase::Both)),
+                None => Some(Phase::Both),
+            },
+        }
+    }
+}
+
+/// Warning that an [`Effect`] can never occur as its parts occur in different phases.
+/// - ex. `Sell` -> `If in battle, gain +1 attack.`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PhaseWarning {
+    /// Part of effect and its phase.
+    /// * Parts occurring in both phases are omitted.
+    pub parts: Vec<(String, Phase)>,
+}
+
+impl Display for PhaseWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parts: Vec<String> = self
+            .parts
+            .iter()
+            .map(|(part, phase)| format!("{part} occurs in {phase}"))
+            .collect();
+        write!(f, "Effect can never occur: {}.", parts.join(", "))
+    }
+}
+
+/// Get phase of each part of an [`Effect`].
+///
+/// ### Params
+/// * `effect`
+///     * [`Effect`]
+///
+/// ### Returns
+/// * Name of part and its phase or [`None`] if part can never occur.
+fn effect_phase_parts(effect: &Effect) -> Vec<(&'static str, Option<Phase>)> {
+    let mut parts = vec![];
+    if let Some(trigger) = &effect.trigger {
+        parts.push(("trigger", Some(Phase::from_trigger(trigger))));
+    }
+    if let Some(condition) = &effect.condition {
+        parts.push(("condition", Phase::from_condition(condition)));
+    }
+    match effect.action {
+        Some(action) if action.is_shop_related() => parts.push(("action", Some(Phase::Shop))),
+        Some(action) if action.is_battle_related() => parts.push(("action", Some(Phase::Battle))),
+        _ => {}
+    }
+    if effect.target == Some(TargetType::Shop) || effect.shop.is_some() {
+        parts.push(("target", Some(Phase::Shop)));
+    } else if effect.target == Some(TargetType::Enemy) {
+        // Enemies only exist in battle.
+        parts.push(("target", Some(Phase::Battle)));
+    }
+    parts
+}
+
+impl<'src> EffectTrigger<'src> {
+    /// Get phase trigger can occur in.
+    /// * See [`Phase::from_trigger`].
+    pub fn phase(&self) -> Phase {
+        Phase::from_trigger(self)
+    }
+}
+
+impl<'src> Effect<'src> {
+    /// Get phase effect can occur in.
+    /// * Includes [`Effect::trigger`] and [`Effect::condition`].
+    ///
+    /// ### Returns
+    /// * [`Phase`] or [`None`] if effect can never occur.
+    pub fn phase(&self) -> Option<Phase> {
+        effect_phase_parts(self)
+            .into_iter()
+            .try_fold(Phase::Both, |phase, (_, part_phase)| {
+                phase.intersect(part_phase?)
+            })
+    }
+
+    /// Check that all parts of effect can occur in the same phase.
+    ///
+    /// ### Returns
+    /// * [`PhaseWarning`] if effect can never occur.
+    ///
+    /// ```
+    /// use saplex::ability::Ability;
+    ///
+    /// let abilities = Ability::parse("Sell: If in battle, gain +1 attack.").unwrap();
+    /// let effect = &abilities[0].effects[0];
+    /// assert_eq!(effect.phase(), None);
+    /// assert_eq!(
+    ///     effect.check_phase().unwrap().to_string(),
+    ///     "Effect can never occur: trigger occurs in shop, condition occurs in battle."
+    /// );
+    /// ```
+    pub fn check_phase(&self) -> Option<PhaseWarning> {
+        if self.phase().is_some() {
+            return None;
+        }
+        let parts = effect_phase_parts(self)
+            .into_iter()
+            .filter_map(|(part, phase)| match phase {
+                Some(Phase::Both) => None,
+                Some(phase) => Some((part.to_owned(), phase)),
+                // Condition can never be met by itself.
+                None => Some((part.to_owned(), Phase::Both)),
+            })
+            .collect();
+        Some(PhaseWarning { parts })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ability::Ability, EffectTrigger, SAPText};
+
+    use super::Phase;
+
+    #[test]
+    fn test_trigger_phase() {
+        let texts = [
+            ("Start of battle", Phase::Battle),
+            ("Before attack", Phase::Battle),
+            ("Friend ahead hurt", Phase::Battle),
+            ("End turn", Phase::Shop),
+            ("Start of turn", Phase::Shop),
+            ("Sell", Phase::Shop),
+            ("Roll", Phase::Shop),
+            ("Faint", Phase::Both),
+            ("Friend summoned", Phase::Both),
+        ];
+        for (txt, phase) in texts {
+            let triggers: Vec<EffectTrigger> =
+                SAPText::new(txt).tokenize().unwrap().try_into().unwrap();
+            assert_eq!(triggers[0].phase(), phase, "{txt}");
+        }
+    }
+
+    #[test]
+    fn test_effect_phase() {
+        let compatible = Ability::parse("Faint: If in battle, gain +1 attack.").unwrap();
+        assert_eq!(compatible[0].effects[0].phase(), Some(Phase::Battle));
+        assert_eq!(compatible[0].effects[0].check_phase(), None);
+
+        let shop = Ability::parse("End turn: Stock a free Melon Perk.").unwrap();
+        assert_eq!(shop[0].effects[0].phase(), Some(Phase::Shop));
+
+        let incompatible =
+            Ability::parse("Start of battle: Discount shop food by 1 gold.").unwrap();
+        let warning = incompatible[0].effects[0].check_phase().unwrap();
+        assert_eq!(
+            warning.parts,
+            [
+                ("trigger".to_owned(), Phase::Battle),
+                ("action".to_owned(), Phase::Shop),
+                ("target".to_owned(), Phase::Shop)
+            ]
+        );
+        assert_eq!(incompatible[0].phase_warnings(), [warning]);
+
+        let battle = Ability::parse("Faint: Deal 2 damage to one random enemy.").unwrap();
+        assert_eq!(battle[0].effects[0].phase(), Some(Phase::Battle));
+
+        let incompatible = Ability::parse("Sell: Deal 2 damage to one random enemy.").unwrap();
+        let warning = incompatible[0].effects[0].check_phase().unwrap();
+        assert_eq!(
+            warning.parts,
+            [
+                ("trigger".to_owned(), Phase::Shop),
+                ("action".to_owned(), Phase::Battle),
+                ("target".to_owned(), Phase::Battle)
+            ]
+        );
+    }
+}
@@ -0,0 +1,186 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    compare::split_stat,
+    effect::Effect,
+    token::{
+        actions::ActionType, attribute::EntityType, position::PositionType, target::TargetType,
+    },
+};
+
+/// Pet whose ability is referenced.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AbilityOwner<'src> {
+    /// Pet causing the effect to trigger.
+    /// - ex. `Activate its ability again.`
+    Trigger,
+    /// Specific pet.
+    /// - ex. `Copy the ability of Parrot.`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    Pet(EntityType<'src>),
+    /// Pet selected at runtime.
+    /// - ex. `Copy the ability of the highest tier friend.`
+    Selected {
+        /// Target to select from.
+        /// * [`None`] with a [`PositionType::OnSelf`] position indicates this pet.
+        target: Option<TargetType>,
+        /// Position of the selected pet.
+        position: Vec<PositionType>,
+        /// Stat ranked by [`PositionType::Highest`] or [`PositionType::Lowest`].
+        /// * Inner value is always [`None`].
+        /// - ex. `tier`
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        stat: Option<EntityType<'src>>,
+    },
+}
+
+/// Reference to another pet's ability resolved at runtime.
+/// - ex. `Activate the ability of the friend ahead 2 times.`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AbilityRef<'src> {
+    /// Pet whose ability is referenced.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub owner: AbilityOwner<'src>,
+    /// Level of ability used.
+    /// * [`None`] indicates the owner's current level.
+    /// - ex. `as if they were level 2`
+    pub level: Option<usize>,
+    /// Number of times the ability is activated.
+    /// - ex. `2 times`
+    pub count: usize,
+}
+
+impl<'src> AbilityRef<'src> {
+    /// Create [`AbilityRef`] from an [`Effect`] with a [`ActionType::Activate`] or [`ActionType::Copy`] action affecting an ability.
+    ///
+    /// ### Params
+    /// * `effect`
+    ///     * [`Effect`]
+    /// * `count`
+    ///     * Number of times the ability is activated, if given.
+    ///     * Defaults to `1`.
+    ///
+    /// ### Returns
+    /// * [`AbilityRef`] or [`None`] if not an ability reference.
+    pub(crate) fn from_effect(effect: &Effect<'src>, count: Option<usize>) -> Option<Self> {
+        if !matches!(effect.action, Some(ActionType::Activate | ActionType::Copy))
+            || !effect
+                .entities
+                .iter()
+                .any(|entity| matches!(entity, EntityType::Ability(_)))
+        {
+            return None;
+        }
+        let pet = effect
+            .entities
+            .iter()
+            .find(|entity| matches!(entity, EntityType::Pet { name: Some(_), .. }));
+        let owner = if effect.position.contains(&PositionType::Trigger) {
+            AbilityOwner::Trigger
+        } else if let Some(pet) = pet {
+            AbilityOwner::Pet(pet.clone())
+        } else {
+            let mut position = effect.position.clone();
+            if position.is_empty() && effect.target.is_none() {
+                position.push(PositionType::OnSelf)
+            }
+            // Level is the level of the ability rather than a ranked stat.
+            let stat = effect
+                .entities
+                .iter()
+                .filter_map(split_stat)
+                .find(|(stat, value)| value.is_none() && *stat != EntityType::Level(None))
+                .map(|(stat, _)| stat);
+            AbilityOwner::Selected {
+                target: effect.target,
+                position,
+                stat,
+            }
+        };
+        let level = effect.entities.iter().find_map(|entity| match entity {
+            EntityType::Level(Some(lvl)) => usize::try_from(*lvl).ok(),
+            _ => None,
+        });
+
+        Some(AbilityRef {
+            owner,
+            level,
+            count: count.unwrap_or(1),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AbilityOwner, AbilityRef};
+    use crate::{
+        token::{attribute::EntityType, position::PositionType, target::TargetType},
+        Effect, SAPText,
+    };
+
+    /// Parse ability reference of first effect.
+    fn parse(txt: &str) -> Option<AbilityRef<'_>> {
+        let tokens = SAPText::new(txt).tokenize().unwrap();
+        Effect::new(None, &tokens).unwrap().remove(0).ability_ref
+    }
+
+    #[test]
+    fn test_ability_ref_owner() {
+        assert_eq!(
+            parse("Activate its ability again."),
+            Some(AbilityRef {
+                owner: AbilityOwner::Trigger,
+                level: None,
+                count: 1
+            })
+        );
+        assert_eq!(
+            parse("Copy the ability of the highest tier friend."),
+            Some(AbilityRef {
+                owner: AbilityOwner::Selected {
+                    target: Some(TargetType::Friend),
+                    position: vec![PositionType::Highest],
+                    stat: Some(EntityType::Tier(None))
+                },
+                level: None,
+                count: 1
+            })
+        );
+        assert_eq!(
+            parse("Copy the level 2 ability of Parrot."),
+            Some(AbilityRef {
+                owner: AbilityOwner::Pet(EntityType::Pet {
+                    number: None,
+                    name: Some("Parrot"),
+                    attr: None
+                }),
+                level: Some(2),
+                count: 1
+            })
+        );
+        assert_eq!(parse("Copy the attack of the friend ahead."), None);
+    }
+
+    #[test]
+    fn test_ability_ref_level_and_count() {
+        assert_eq!(
+            parse("Activate the ability of the friend ahead 2 times."),
+            Some(AbilityRef {
+                owner: AbilityOwner::Selected {
+                    target: Some(TargetType::Friend),
+                    position: vec![PositionType::Ahead],
+                    stat: None
+                },
+                level: None,
+                count: 2
+            })
+        );
+        let ability_ref =
+            parse("The friend ahead repeats their ability in battle as if they were level 2.")
+                .unwrap();
+        assert_eq!(ability_ref.level, Some(2));
+    }
+}
@@ -4,7 +4,7 @@ use std::fmt::Display;
 use serde::{Deserialize, Serialize};
 
 /// [`SAPText`] parser state.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Scanner {
     /// Start character index of lexeme.
@@ -41,6 +41,21 @@ impl Scanner {
         self
     }
 
+    /// Join with another span into one span covering both.
+    /// * [`Scanner::line`] is the line of the earlier span.
+    pub fn join(&self, other: &Scanner) -> Scanner {
+        let first = if other.start < self.start {
+            other
+        } else {
+            self
+        };
+        Scanner {
+            start: self.start.min(other.start),
+            current: self.current.max(other.current),
+            line: first.line,
+        }
+    }
+
     /// Set [`Scanner::start`] to [`Scanner::current`].
     pub(crate) fn set_start_to_current(&mut self) -> &mut Self {
         self.start = self.current;
@@ -0,0 +1,130 @@
+use std::fmt::Display;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    effect::Effect,
+    token::{actions::ActionType, attribute::EntityType},
+};
+
+/// Kind of shop slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ShopSlot {
+    /// Shop pet slot.
+    Pet,
+    /// Shop food slot.
+    Food,
+}
+
+/// Change in price of shop items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PriceChange {
+    /// Item is free.
+    /// - ex. `Stock a free Melon Perk.`
+    Free,
+    /// Item is discounted by some amount of gold.
+    /// - ex. `Discount shop food by 1 gold.`
+    Discount(i32),
+}
+
+impl Display for ShopSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ShopSlot::Pet => "pet",
+            ShopSlot::Food => "food",
+        })
+    }
+}
+
+impl Display for PriceChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PriceChange::Free => f.write_str("free"),
+            PriceChange::Discount(gold) => write!(f, "{gold} gold off"),
+        }
+    }
+}
+
+/// A shop effect.
+/// - ex. `Freeze the left-most shop pet.`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ShopEffect<'src> {
+    /// Shop slot affected.
+    /// * [`None`] indicates any slot.
+    pub slot: Option<ShopSlot>,
+    /// Number of items affected.
+    /// * [`None`] indicates all items.
+    pub count: Option<usize>,
+    /// Change in price of items.
+    pub price_change: Option<PriceChange>,
+    /// If items are frozen or unfrozen.
+    pub frozen: Option<bool>,
+    /// Specific item.
+    /// - ex. `Melon Perk`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub item: Option<EntityType<'src>>,
+}
+
+impl<'src> ShopEffect<'src> {
+    /// Create [`ShopEffect`] from an [`Effect`] with a [`ActionType::Stock`], [`ActionType::Discount`], [`ActionType::Freeze`], [`ActionType::Unfreeze`], or [`ActionType::Roll`] action.
+    /// * A [`PriceChange::Free`] already in [`Effect::shop`] is kept.
+    ///
+    /// ### Params
+    /// * `effect`
+    ///     * [`Effect`]
+    /// * `count`
+    ///     * Number of items affected, if given.
+    ///
+    /// ### Returns
+    /// * [`ShopEffect`] or [`None`] if not a shop effect.
+    pub(crate) fn from_effect(effect: &Effect<'src>, count: Option<usize>) -> Option<Self> {
+        let action = effect.action.filter(ActionType::is_shop_effect)?;
+        let slot = effect.entities.iter().find_map(|entity| match entity {
+            EntityType::Pet { .. } => Some(ShopSlot::Pet),
+            EntityType::Food { .. } | EntityType::Perk { .. } => Some(ShopSlot::Food),
+            _ => None,
+        });
+        // Free items are set while parsing.
+        let price_change = effect
+            .shop
+            .as_ref()
+            .and_then(|shop| shop.price_change)
+            .or_else(|| {
+                effect.entities.iter().find_map(|entity| match entity {
+                    EntityType::Gold(Some(gold)) if action == ActionType::Discount => {
+                        Some(PriceChange::Discount(*gold))
+                    }
+                    _ => None,
+                })
+            });
+        let frozen = match action {
+            ActionType::Freeze => Some(true),
+            ActionType::Unfreeze => Some(false),
+            _ => None,
+        };
+        let item = effect
+            .entities
+            .iter()
+            .find(|entity| {
+                matches!(
+                    entity,
+                    EntityType::Pet { name: Some(_), .. }
+                        | EntityType::Food { name: Some(_), .. }
+                        | EntityType::Perk { name: Some(_), .. }
+                )
+            })
+            .cloned();
+
+        Some(ShopEffect {
+            slot,
+            count,
+            price_change,
+            frozen,
+            item,
+        })
+    }
+}
@@ -0,0 +1,197 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    effect::Effect,
+    scanner::Scanner,
+    token::{types::TokenType, Token},
+    value::Amount,
+};
+
+/// Source spans of the tokens used to create each field of an [`Effect`].
+/// * Fields with no span were implied rather than given in the text.
+/// - ex. `Gain +1 attack.` has no span for its implied [`PositionType::OnSelf`](crate::token::PositionType::OnSelf) position.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EffectSpans {
+    /// Span of [`Effect::cond_trigger`].
+    /// - ex. `for each Strawberry friend`
+    pub cond_trigger: Option<Scanner>,
+    /// Span of [`Effect::condition`].
+    /// - ex. `If this has a level 3 friend`
+    pub condition: Option<Scanner>,
+    /// Span of [`Effect::target`].
+    pub target: Option<Scanner>,
+    /// Span of each of [`Effect::entities`].
+    pub entities: Vec<Scanner>,
+    /// Span of each of [`Effect::amounts`].
+    pub amounts: Vec<Scanner>,
+    /// Span of the number of items affected.
+    /// * Used by [`Effect::target_count`], [`Effect::summon`], [`Effect::shop`], and [`Effect::ability_ref`].
+    /// - ex. `Summon two 2/2 Rams.`
+    pub count: Option<Scanner>,
+    /// Span of each of [`Effect::comparisons`].
+    pub comparisons: Vec<Scanner>,
+    /// Span of each of [`Effect::excluded`].
+    /// - ex. `without a perk`
+    pub excluded: Vec<Scanner>,
+    /// Span of each of [`Effect::position`].
+    /// * [`None`] if position is implied.
+    pub position: Vec<Option<Scanner>>,
+    /// Span of [`Effect::action`].
+    pub action: Option<Scanner>,
+    /// Span of [`Effect::uses`] and [`Effect::usage`].
+    pub uses: Option<Scanner>,
+    /// Span of [`Effect::duration`].
+    pub duration: Option<Scanner>,
+    /// Span of all tokens used to create the effect.
+    /// * Also the span of [`Effect::summon`] and [`Effect::shop`].
+    pub effect: Option<Scanner>,
+}
+
+impl EffectSpans {
+    /// Update spans of fields set when an [`Effect`] is finalized.
+    /// * Adds spans of amounts from entities, pads implied positions, and sets [`EffectSpans::effect`].
+    ///
+    /// ### Params
+    /// * `effect`
+    ///     * Finalized [`Effect`] these spans belong to.
+    pub(crate) fn finalize(&mut self, effect: &Effect) {
+        let mut amounts: Vec<Scanner> = effect
+            .entities
+            .iter()
+            .zip(self.entities.iter())
+            .filter(|(entity, _)| Amount::from_entity(entity, effect.action).is_some())
+            .map(|(_, span)| span.clone())
+            .collect();
+        amounts.append(&mut self.amounts);
+        self.amounts = amounts;
+        self.position.resize(effect.position.len(), None);
+
+        self.effect = [
+            &self.cond_trigger,
+            &self.condition,
+            &self.target,
+            &self.count,
+            &self.action,
+            &self.uses,
+            &self.duration,
+        ]
+        .into_iter()
+        .flatten()
+        .chain(self.entities.iter())
+        .chain(self.amounts.iter())
+        .chain(self.comparisons.iter())
+        .chain(self.excluded.iter())
+        .chain(self.position.iter().flatten())
+        .fold(None, |span: Option<Scanner>, other| {
+            Some(span.map_or_else(|| other.clone(), |span| span.join(other)))
+        });
+    }
+}
+
+/// Get span of tokens consumed from `tokens`.
+/// * Tokens start at `from` and end before `next`, the next unconsumed token.
+/// * [`TokenType::EndText`] is excluded.
+///
+/// ### Params
+/// * `tokens`
+///     * All tokens.
+/// * `from`
+///     * First consumed token in `tokens`.
+/// * `next`
+///     * Next unconsumed token in `tokens` or [`None`] if all consumed.
+///
+/// ### Returns
+/// * [`Scanner`] span.
+pub(crate) fn consumed_span(tokens: &[Token], from: &Token, next: Option<&Token>) -> Scanner {
+    let position = |token: &Token| tokens.iter().position(|tkn| std::ptr::eq(tkn, token));
+    let (Some(start), end) = (position(from), next.and_then(position)) else {
+        return from.metadata.clone();
+    };
+    tokens[start..end.unwrap_or(tokens.len())]
+        .iter()
+        .filter(|token| token.ttype != TokenType::EndText)
+        .fold(from.metadata.clone(), |span, token| {
+            span.join(&token.metadata)
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{scanner::Scanner, token::position::PositionType, Effect, SAPText};
+
+    /// Get text of span.
+    fn span_text<'a>(text: &'a str, span: &Scanner) -> &'a str {
+        &text[span.start..span.current]
+    }
+
+    #[test]
+    fn test_effect_spans() {
+        let txt = "If in battle, gain +1 attack or +1 health until end of turn.";
+        let tokens = SAPText::new(txt).tokenize().unwrap();
+        let effects = Effect::new_with_spans(None, &tokens).unwrap();
+        assert_eq!(effects.len(), 2);
+
+        let (effect, spans) = &effects[1];
+        assert_eq!(effect.entities.len(), 1);
+        assert_eq!(
+            span_text(txt, spans.condition.as_ref().unwrap()),
+            "If in battle"
+        );
+        assert_eq!(span_text(txt, spans.action.as_ref().unwrap()), "gain");
+        assert_eq!(span_text(txt, &spans.entities[0]), "+1 health");
+        assert_eq!(span_text(txt, &spans.amounts[0]), "+1 health");
+        assert_eq!(
+            span_text(txt, spans.duration.as_ref().unwrap()),
+            "until end of turn"
+        );
+        // Implied position.
+        assert_eq!(spans.position, [None]);
+        assert_eq!(
+            span_text(txt, spans.effect.as_ref().unwrap()),
+            "If in battle, gain +1 attack or +1 health until end of turn"
+        );
+
+        let txt = "Gain +1 attack and give the left-most friend +2 attack damage.";
+        let tokens = SAPText::new(txt).tokenize().unwrap();
+        let effects = Effect::new_with_spans(None, &tokens).unwrap();
+        let (_, spans) = &effects[1];
+        assert_eq!(span_text(txt, spans.action.as_ref().unwrap()), "give");
+        assert_eq!(span_text(txt, spans.target.as_ref().unwrap()), "friend");
+        assert_eq!(
+            span_text(txt, spans.position[0].as_ref().unwrap()),
+            "left-most"
+        );
+        assert_eq!(span_text(txt, &spans.entities[0]), "+2 attack damage");
+    }
+
+    #[test]
+    fn test_effect_spans_count_and_uses() {
+        let txt = "Summon two 2/2 Rams. Works 2 times per battle.";
+        let tokens = SAPText::new(txt).tokenize().unwrap();
+        let effects = Effect::new_with_spans(None, &tokens).unwrap();
+        let (_, spans) = &effects[0];
+
+        assert_eq!(span_text(txt, spans.count.as_ref().unwrap()), "two");
+        assert_eq!(
+            span_text(txt, spans.uses.as_ref().unwrap()),
+            "Works 2 times per battle"
+        );
+    }
+
+    #[test]
+    fn test_effect_spans_max_min_position() {
+        let txt = "Give the friend with the most attack +1 health.";
+        let tokens = SAPText::new(txt).tokenize().unwrap();
+        assert!(Effect::new_strict(None, &tokens).is_ok());
+
+        let effects = Effect::new_with_spans(None, &tokens).unwrap();
+        let (effect, spans) = &effects[0];
+        assert_eq!(effect.position, [PositionType::Strongest]);
+        assert_eq!(
+            span_text(txt, spans.position[0].as_ref().unwrap()),
+            "most attack"
+        );
+    }
+}
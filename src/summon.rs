@@ -0,0 +1,100 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    effect::Effect,
+    token::{actions::ActionType, attribute::EntityType, position::PositionType},
+    value::Value,
+};
+
+/// Specification of pets to summon.
+/// - ex. `Summon two 2/2 Rams at the front.`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SummonSpec<'src> {
+    /// Number of pets summoned.
+    pub count: usize,
+    /// Pet summoned.
+    /// - ex. `Zombie Cricket`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub pet: Option<EntityType<'src>>,
+    /// Attack of pet.
+    /// * [`None`] indicates the pet's default attack.
+    pub attack: Option<Value<'src>>,
+    /// Health of pet.
+    /// * [`None`] indicates the pet's default health.
+    pub health: Option<Value<'src>>,
+    /// Level of pet.
+    /// - ex. `as a level 2 pet`
+    pub level: Option<usize>,
+    /// Placement of pet.
+    /// - ex. `at the front`
+    pub placement: Vec<PositionType>,
+    /// Items the pet holds or inherits.
+    /// - ex. `Bus with Chili`
+    pub inherits: Vec<EntityType<'src>>,
+}
+
+impl<'src> SummonSpec<'src> {
+    /// Create [`SummonSpec`] from an [`Effect`] with a [`ActionType::Summon`], [`ActionType::Resummon`], or [`ActionType::Transform`] action.
+    /// * Should be called after [`Effect::amounts`] are updated.
+    ///
+    /// ### Params
+    /// * `effect`
+    ///     * [`Effect`]
+    /// * `count`
+    ///     * Number of pets summoned, if given.
+    ///     * Defaults to `1`.
+    ///
+    /// ### Returns
+    /// * [`SummonSpec`] or [`None`] if not a summon effect.
+    pub(crate) fn from_effect(effect: &Effect<'src>, count: Option<usize>) -> Option<Self> {
+        if !matches!(
+            effect.action,
+            Some(ActionType::Summon | ActionType::Resummon | ActionType::Transform)
+        ) {
+            return None;
+        }
+        let stat_value = |stat: EntityType| {
+            effect
+                .amounts
+                .iter()
+                .find(|amount| amount.stat == stat)
+                .map(|amount| amount.value.clone())
+        };
+        // Prefer named pets over any pet.
+        let pet = effect
+            .entities
+            .iter()
+            .filter(|entity| matches!(entity, EntityType::Pet { .. }))
+            .max_by_key(|entity| {
+                matches!(entity, EntityType::Pet { name, attr, .. } if name.is_some() || attr.is_some())
+            })
+            .cloned();
+        let level = effect.entities.iter().find_map(|entity| match entity {
+            EntityType::Level(Some(lvl)) => usize::try_from(*lvl).ok(),
+            _ => None,
+        });
+        let inherits = effect
+            .entities
+            .iter()
+            .filter(|entity| {
+                matches!(
+                    entity,
+                    EntityType::Food { .. } | EntityType::Perk { .. } | EntityType::Ailment { .. }
+                )
+            })
+            .cloned()
+            .collect();
+
+        Some(SummonSpec {
+            count: count.unwrap_or(1),
+            pet,
+            attack: stat_value(EntityType::Attack(None)),
+            health: stat_value(EntityType::Health(None)),
+            level,
+            placement: effect.position.clone(),
+            inherits,
+        })
+    }
+}
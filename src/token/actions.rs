@@ -1,14 +1,12 @@
 //! SAP action tokens.
 
-use std::str::FromStr;
-
-use anyhow::bail;
+use std::fmt::Display;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[allow(missing_docs)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ActionType {
     Choose,
@@ -39,6 +37,7 @@ pub enum ActionType {
     Shuffle,
     Freeze,
     Unfreeze,
+    Roll,
 
     // Non-Effect
     Attack,
@@ -46,11 +45,31 @@ pub enum ActionType {
     Buy,
     Sell,
     Upgrade,
+    LevelUp,
     Hurt,
     Faint,
+    Win,
+    Lose,
 }
 
 impl ActionType {
+    /// Check if action is an effect on the shop.
+    /// * See [`ShopEffect`](crate::shop::ShopEffect).
+    pub(crate) fn is_shop_effect(&self) -> bool {
+        matches!(
+            self,
+            Self::Stock | Self::Discount | Self::Freeze | Self::Unfreeze | Self::Roll
+        )
+    }
+
+    /// Check if action only occurs in battle.
+    pub(crate) fn is_battle_related(&self) -> bool {
+        matches!(
+            self,
+            Self::Deal | Self::Knock | Self::Resummon | Self::Attack | Self::Hurt
+        )
+    }
+
     /// Check if shop related.
     pub(crate) fn is_shop_related(&self) -> bool {
         matches!(
@@ -60,6 +79,7 @@ impl ActionType {
                 | Self::Discount
                 | Self::Freeze
                 | Self::Unfreeze
+                | Self::Roll
                 | Self::Eat
                 | Self::Buy
                 | Self::Sell
@@ -68,47 +88,55 @@ impl ActionType {
     }
 }
 
-impl FromStr for ActionType {
-    type Err = anyhow::Error;
+token_vocab! {
+    ActionType {
+        "choose" => ActionType::Choose,
+        "deal" => ActionType::Deal,
+        "gain" | "gained" => ActionType::Gain,
+        "give" => ActionType::Give,
+        "push" | "pushed" => ActionType::Push,
+        "remove" => ActionType::Remove,
+        "set" => ActionType::Set,
+        "spend" => ActionType::Spend,
+        "stock" => ActionType::Stock,
+        "summon" | "summoned" => ActionType::Summon,
+        "swap" => ActionType::Swap,
+        "break" | "broke" => ActionType::Break,
+        "copy" => ActionType::Copy,
+        "make" => ActionType::Make,
+        "increase" => ActionType::Increase,
+        "resummon" => ActionType::Resummon,
+        "steal" => ActionType::Steal,
+        "activate" | "repeat" | "repeats" => ActionType::Activate,
+        "discount" => ActionType::Discount,
+        "knock" | "knock-out" | "knocked" => ActionType::Knock,
+        "reduce" => ActionType::Reduce,
+        "swallow" => ActionType::Swallow,
+        "take" => ActionType::Take,
+        "transform" => ActionType::Transform,
+        "replace" => ActionType::Replace,
+        "shuffle" => ActionType::Shuffle,
+        "freeze" => ActionType::Freeze,
+        "unfreeze" => ActionType::Unfreeze,
+        "roll" | "rolls" | "rolled" => ActionType::Roll,
+        "attack" | "attacks" => ActionType::Attack,
+        "eat" | "eats" => ActionType::Eat,
+        "buy" | "bought" => ActionType::Buy,
+        "upgrade" => ActionType::Upgrade,
+        "level-up" | "levels-up" | "leveled-up" => ActionType::LevelUp,
+        "hurt" | "hurts" => ActionType::Hurt,
+        "sell" | "sold" => ActionType::Sell,
+        "faint" | "faints" | "fainting" => ActionType::Faint,
+        "win" | "wins" | "won" => ActionType::Win,
+        "lose" | "loses" | "lost" => ActionType::Lose,
+    }
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "choose" => ActionType::Choose,
-            "deal" => ActionType::Deal,
-            "gain" | "gained" => ActionType::Gain,
-            "give" => ActionType::Give,
-            "push" | "pushed" => ActionType::Push,
-            "remove" => ActionType::Remove,
-            "set" => ActionType::Set,
-            "spend" => ActionType::Spend,
-            "stock" => ActionType::Stock,
-            "summon" | "summoned" => ActionType::Summon,
-            "swap" => ActionType::Swap,
-            "break" | "broke" => ActionType::Break,
-            "copy" => ActionType::Copy,
-            "make" => ActionType::Make,
-            "increase" => ActionType::Increase,
-            "resummon" => ActionType::Resummon,
-            "steal" => ActionType::Steal,
-            "activate" => ActionType::Activate,
-            "discount" => ActionType::Discount,
-            "knock" | "knock-out" | "knocked" => ActionType::Knock,
-            "reduce" => ActionType::Reduce,
-            "swallow" => ActionType::Swallow,
-            "take" => ActionType::Take,
-            "transform" => ActionType::Transform,
-            "replace" => ActionType::Replace,
-            "shuffle" => ActionType::Shuffle,
-            "freeze" => ActionType::Freeze,
-            "unfreeze" => ActionType::Unfreeze,
-            "attack" | "attacks" => ActionType::Attack,
-            "eat" | "eats" => ActionType::Eat,
-            "buy" | "bought " => ActionType::Buy,
-            "upgrade" => ActionType::Upgrade,
-            "hurt" => ActionType::Hurt,
-            "sell" | "sold" => ActionType::Sell,
-            "faint" | "faints" | "fainting" => ActionType::Faint,
-            _ => bail!("Unknown action. {s}"),
-        })
+impl Display for ActionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.word() {
+            Some(word) => f.write_str(word),
+            None => write!(f, "{self:?}"),
+        }
     }
 }
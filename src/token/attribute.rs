@@ -1,18 +1,18 @@
 //! SAP item attribute/entity tokens.
 
-use std::str::FromStr;
+use std::fmt::Display;
 
 use anyhow::bail;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use super::ParseNumber;
+use super::{percent::Percent, ParseNumber};
 
 /// All possible entity types in Super Auto Pets.
 /// - If [`None`], the entity itself.
 ///     - ex. `EntityType::Battle(None)` -> `battle`
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum EntityType<'src> {
     /// Pet.
@@ -37,13 +37,27 @@ pub enum EntityType<'src> {
     /// Effect ability.
     Ability(Option<&'src str>),
     /// Food perk.
-    Perk(Option<i32>),
+    Perk {
+        /// Associated number of perks.
+        number: Option<i32>,
+        /// Specific perk name.
+        /// - ex. `Melon`
+        name: Option<&'src str>,
+    },
     /// Ailment.
-    Ailment(Option<i32>),
+    Ailment {
+        /// Associated number of ailments.
+        number: Option<i32>,
+        /// Specific ailment name.
+        /// - ex. `Weak`
+        name: Option<&'src str>,
+    },
     /// Spaces.
     Space(Option<i32>),
     /// Phases of battle.
     Battle(Option<i32>),
+    /// Games.
+    Game(Option<i32>),
     /// Turns.
     Turn(Option<i32>),
     /// Attack.
@@ -66,20 +80,20 @@ pub enum EntityType<'src> {
     Experience(Option<i32>),
 
     /// Attack percent.
-    AttackPercent(Option<f32>),
+    AttackPercent(Option<Percent>),
     /// Health percent.
-    HealthPercent(Option<f32>),
+    HealthPercent(Option<Percent>),
     /// Damage percent.
-    DamagePercent(Option<f32>),
+    DamagePercent(Option<Percent>),
     /// Gold percent.
-    GoldPercent(Option<f32>),
+    GoldPercent(Option<Percent>),
     /// Trumpet percent.
-    TrumpetPercent(Option<f32>),
+    TrumpetPercent(Option<Percent>),
 }
 
 impl<'src> EntityType<'src> {
     /// Value of inner item, if any.
-    /// * [`f32`] are coerced to [`i32`] which in SAP values shouldn't be an issue.
+    /// * [`Percent`]s are truncated to whole percents. See [`Percent::whole`].
     pub(crate) fn value(&self) -> Option<i32> {
         match self {
             EntityType::Attack(v)
@@ -92,20 +106,158 @@ impl<'src> EntityType<'src> {
             | EntityType::Uses(v)
             | EntityType::Pet { number: v, .. }
             | EntityType::Food { number: v, .. }
-            | EntityType::Perk(v)
-            | EntityType::Ailment(v)
+            | EntityType::Perk { number: v, .. }
+            | EntityType::Ailment { number: v, .. }
             | EntityType::Space(v)
             | EntityType::Turn(v)
             | EntityType::Battle(v)
+            | EntityType::Game(v)
             | EntityType::Experience(v) => *v,
             EntityType::AttackPercent(v)
             | EntityType::HealthPercent(v)
             | EntityType::DamagePercent(v)
             | EntityType::GoldPercent(v)
-            | EntityType::TrumpetPercent(v) => v.map(|val| val as i32),
+            | EntityType::TrumpetPercent(v) => v.map(|val| val.whole()),
             EntityType::Toy(_) | EntityType::Ability { .. } => None,
         }
     }
+
+    /// Text of inner value, if any.
+    /// * [`Percent`]s keep any fraction.
+    /// - ex. `2`, `12.5%`
+    pub(crate) fn value_text(&self) -> Option<String> {
+        match self {
+            EntityType::AttackPercent(perc)
+            | EntityType::HealthPercent(perc)
+            | EntityType::DamagePercent(perc)
+            | EntityType::GoldPercent(perc)
+            | EntityType::TrumpetPercent(perc) => perc.map(|perc| perc.to_string()),
+            entity => entity.value().map(|value| value.to_string()),
+        }
+    }
+
+    /// Map inner value, if any.
+    /// * [`Percent`]s are unchanged.
+    pub(crate) fn map_value(&mut self, f: impl FnOnce(i32) -> i32) {
+        match self {
+            EntityType::Attack(Some(v))
+            | EntityType::Damage(Some(v))
+            | EntityType::Health(Some(v))
+            | EntityType::Gold(Some(v))
+            | EntityType::Trumpet(Some(v))
+            | EntityType::Level(Some(v))
+            | EntityType::Tier(Some(v))
+            | EntityType::Uses(Some(v))
+            | EntityType::Pet {
+                number: Some(v), ..
+            }
+            | EntityType::Food {
+                number: Some(v), ..
+            }
+            | EntityType::Perk {
+                number: Some(v), ..
+            }
+            | EntityType::Ailment {
+                number: Some(v), ..
+            }
+            | EntityType::Space(Some(v))
+            | EntityType::Turn(Some(v))
+            | EntityType::Battle(Some(v))
+            | EntityType::Game(Some(v))
+            | EntityType::Experience(Some(v)) => *v = f(*v),
+            _ => {}
+        }
+    }
+
+    /// Get entity with its inner value, if any, set to zero.
+    /// - ex. `Attack(Some(2))` -> `Attack(Some(0))`
+    /// - ex. `AttackPercent(Some(50%))` -> `AttackPercent(Some(0%))`
+    pub(crate) fn without_value(&self) -> Self {
+        let mut entity = self.clone();
+        match &mut entity {
+            EntityType::AttackPercent(Some(v))
+            | EntityType::HealthPercent(Some(v))
+            | EntityType::DamagePercent(Some(v))
+            | EntityType::GoldPercent(Some(v))
+            | EntityType::TrumpetPercent(Some(v)) => *v = Percent::default(),
+            entity => entity.map_value(|_| 0),
+        }
+        entity
+    }
+
+    /// Name of kind of entity, ignoring any inner value.
+    /// - ex. `EntityType::Attack(Some(2))` -> `Attack`
+    /// - ex. `EntityType::AttackPercent(None)` -> `AttackPercent`
+    pub fn kind_name(&self) -> &'static str {
+        match self {
+            EntityType::Pet { .. } => "Pet",
+            EntityType::Food { .. } => "Food",
+            EntityType::Toy(_) => "Toy",
+            EntityType::Ability(_) => "Ability",
+            EntityType::Perk { .. } => "Perk",
+            EntityType::Ailment { .. } => "Ailment",
+            EntityType::Space(_) => "Space",
+            EntityType::Battle(_) => "Battle",
+            EntityType::Game(_) => "Game",
+            EntityType::Turn(_) => "Turn",
+            EntityType::Attack(_) => "Attack",
+            EntityType::Damage(_) => "Damage",
+            EntityType::Health(_) => "Health",
+            EntityType::Gold(_) => "Gold",
+            EntityType::Trumpet(_) => "Trumpet",
+            EntityType::Level(_) => "Level",
+            EntityType::Tier(_) => "Tier",
+            EntityType::Uses(_) => "Uses",
+            EntityType::Experience(_) => "Experience",
+            EntityType::AttackPercent(_) => "AttackPercent",
+            EntityType::HealthPercent(_) => "HealthPercent",
+            EntityType::DamagePercent(_) => "DamagePercent",
+            EntityType::GoldPercent(_) => "GoldPercent",
+            EntityType::TrumpetPercent(_) => "TrumpetPercent",
+        }
+    }
+}
+
+impl<'src> Display for EntityType<'src> {
+    /// Format entity as in SAP text.
+    /// - ex. `2 attack`, `50% health`, `tier 3`, `Strawberry Ram`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let word = match self {
+            EntityType::Pet { name, .. } => name.unwrap_or("pet"),
+            EntityType::Food { name, .. } => name.unwrap_or("food"),
+            EntityType::Perk { name, .. } => name.unwrap_or("perk"),
+            EntityType::Ailment { name, .. } => name.unwrap_or("ailment"),
+            EntityType::Toy(name) => name.unwrap_or("toy"),
+            EntityType::Ability(name) => name.unwrap_or("ability"),
+            EntityType::Space(_) => "space",
+            EntityType::Battle(_) => "battle",
+            EntityType::Game(_) => "game",
+            EntityType::Turn(_) => "turn",
+            EntityType::Attack(_) | EntityType::AttackPercent(_) => "attack",
+            EntityType::Damage(_) | EntityType::DamagePercent(_) => "damage",
+            EntityType::Health(_) | EntityType::HealthPercent(_) => "health",
+            EntityType::Gold(_) | EntityType::GoldPercent(_) => "gold",
+            EntityType::Trumpet(_) | EntityType::TrumpetPercent(_) => "trumpet",
+            EntityType::Level(_) => "level",
+            EntityType::Tier(_) => "tier",
+            EntityType::Uses(_) => "uses",
+            EntityType::Experience(_) => "experience",
+        };
+        match (self, self.value_text()) {
+            (EntityType::Level(_) | EntityType::Tier(_), Some(value)) => {
+                return write!(f, "{word} {value}")
+            }
+            (_, Some(value)) => write!(f, "{value} ")?,
+            (_, None) => {}
+        }
+        if let EntityType::Pet {
+            attr: Some(attr), ..
+        } = self
+        {
+            write!(f, "{attr} ")?
+        }
+        f.write_str(word)
+    }
 }
 
 impl<'src> ParseNumber for EntityType<'src> {
@@ -126,11 +278,16 @@ impl<'src> ParseNumber for EntityType<'src> {
             | EntityType::Food {
                 number: ref mut v, ..
             }
-            | EntityType::Perk(ref mut v)
-            | EntityType::Ailment(ref mut v)
+            | EntityType::Perk {
+                number: ref mut v, ..
+            }
+            | EntityType::Ailment {
+                number: ref mut v, ..
+            }
             | EntityType::Space(ref mut v)
             | EntityType::Turn(ref mut v)
             | EntityType::Battle(ref mut v)
+            | EntityType::Game(ref mut v)
             | EntityType::Experience(ref mut v) => {
                 v.replace(cleaned_num_str.parse()?);
             }
@@ -148,51 +305,161 @@ impl<'src> ParseNumber for EntityType<'src> {
     }
 }
 
-impl<'src> FromStr for EntityType<'src> {
-    type Err = anyhow::Error;
+impl<'src> EntityType<'src> {
+    /// Names of perks held by pets.
+    /// * Item names ending in `Perk` are also perks.
+    pub const PERKS: &'static [&'static str] = &[
+        "Cheese",
+        "Chili",
+        "Coconut",
+        "Fortune Cookie",
+        "Garlic",
+        "Grapes",
+        "Honey",
+        "Lemon",
+        "Meat Bone",
+        "Melon",
+        "Mushroom",
+        "Peanut",
+        "Pepper",
+        "Popcorn",
+        "Steak",
+    ];
+
+    /// Names of ailments held by pets.
+    pub const AILMENTS: &'static [&'static str] =
+        &["Cold", "Crisp", "Dazed", "Inked", "Spooked", "Weak"];
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "pet" | "pets" => EntityType::Pet {
-                number: None,
-                name: None,
+    /// Names of foods that are eaten rather than held.
+    /// * See [`EntityType::PERKS`] for held foods.
+    pub const FOODS: &'static [&'static str] = &[
+        "Apple",
+        "Broccoli",
+        "Canned Food",
+        "Chocolate",
+        "Cupcake",
+        "Lollipop",
+        "Milk",
+        "Pear",
+        "Pizza",
+        "Salad Bowl",
+        "Sleeping Pill",
+        "Sushi",
+    ];
+
+    /// Convert a named pet or food to a named food, perk, or ailment, if its name is one.
+    /// * See [`EntityType::FOODS`], [`EntityType::PERKS`], and [`EntityType::AILMENTS`].
+    /// * Plural names are made singular.
+    /// - ex. `Pet { name: "Apple" }` -> `Food { name: "Apple" }`
+    /// - ex. `Pet { name: "Apples" }` -> `Food { name: "Apple" }`
+    /// - ex. `Pet { name: "Melon" }` -> `Perk { name: "Melon" }`
+    pub(crate) fn into_known_item(self) -> Self {
+        match self {
+            EntityType::Pet {
+                number,
+                name: Some(name),
                 attr: None,
-            },
-            "food" | "foods" => EntityType::Food {
-                number: None,
-                name: None,
-            },
-            "toy" | "toys" => EntityType::Toy(None),
-            "perk" | "perks" => EntityType::Perk(None),
-            "ailment" | "ailments" => EntityType::Ailment(None),
-            "turn" | "turns" => EntityType::Turn(None),
-            "battle" | "battles" => EntityType::Battle(None),
-            "space" => EntityType::Space(None),
-            "attack" => EntityType::Attack(None),
-            "damage" => EntityType::Damage(None),
-            "health" | "healthy" => EntityType::Health(None),
-            "gold" => EntityType::Gold(None),
-            "trumpet" | "trumpets" => EntityType::Trumpet(None),
-            "level" => EntityType::Level(None),
-            "tier" => EntityType::Tier(None),
-            "uses" => EntityType::Uses(None),
-            "experience" => EntityType::Experience(None),
-            "ability" => EntityType::Ability(None),
-            _ => bail!("Not a valid EntityType {s}"),
-        })
+            }
+            | EntityType::Food {
+                number,
+                name: Some(name),
+            } => {
+                if let Some(name) = find_name(name, EntityType::PERKS) {
+                    EntityType::Perk {
+                        number,
+                        name: Some(name),
+                    }
+                } else if name.ends_with(" Perk") {
+                    EntityType::Perk {
+                        number,
+                        name: Some(name),
+                    }
+                } else if let Some(name) = find_name(name, EntityType::AILMENTS) {
+                    EntityType::Ailment {
+                        number,
+                        name: Some(name),
+                    }
+                } else if let Some(name) = find_name(name, EntityType::FOODS) {
+                    EntityType::Food {
+                        number,
+                        name: Some(name),
+                    }
+                } else {
+                    self
+                }
+            }
+            _ => self,
+        }
+    }
+}
+
+/// Find a name or its singular in a list of item names.
+/// - ex. `Apples` in `["Apple"]` -> `Apple`
+fn find_name<'src>(name: &'src str, names: &[&str]) -> Option<&'src str> {
+    [Some(name), name.strip_suffix('s')]
+        .into_iter()
+        .flatten()
+        .find(|name| names.contains(name))
+}
+
+token_vocab! {
+    EntityType<'src> {
+        "pet" | "pets" => EntityType::Pet {
+            number: None,
+            name: None,
+            attr: None,
+        },
+        "food" | "foods" => EntityType::Food {
+            number: None,
+            name: None,
+        },
+        "toy" | "toys" => EntityType::Toy(None),
+        "perk" | "perks" => EntityType::Perk {
+            number: None,
+            name: None,
+        },
+        "ailment" | "ailments" => EntityType::Ailment {
+            number: None,
+            name: None,
+        },
+        "turn" | "turns" => EntityType::Turn(None),
+        "battle" | "battles" => EntityType::Battle(None),
+        "game" | "games" => EntityType::Game(None),
+        "space" => EntityType::Space(None),
+        "attack" => EntityType::Attack(None),
+        "damage" => EntityType::Damage(None),
+        "health" | "healthy" => EntityType::Health(None),
+        "gold" => EntityType::Gold(None),
+        "trumpet" | "trumpets" => EntityType::Trumpet(None),
+        "level" => EntityType::Level(None),
+        "tier" => EntityType::Tier(None),
+        "uses" => EntityType::Uses(None),
+        "experience" => EntityType::Experience(None),
+        "ability" => EntityType::Ability(None),
     }
 }
 
 impl<'src> EntityType<'src> {
     /// Converts [`EntityType`] variant to a 'percent' labeled variant.
     /// * ex. [`EntityType::Gold`] -> [`EntityType::GoldPercent`]
+    /// * Errors if its value is too large for a [`Percent`]. See [`Percent::try_new`].
     pub fn into_percent_variant(self) -> anyhow::Result<Self> {
         Ok(match self {
-            EntityType::Attack(val) => EntityType::AttackPercent(val.map(|v| v as f32)),
-            EntityType::Damage(val) => EntityType::DamagePercent(val.map(|v| v as f32)),
-            EntityType::Health(val) => EntityType::HealthPercent(val.map(|v| v as f32)),
-            EntityType::Gold(val) => EntityType::GoldPercent(val.map(|v| v as f32)),
-            EntityType::Trumpet(val) => EntityType::TrumpetPercent(val.map(|v| v as f32)),
+            EntityType::Attack(val) => {
+                EntityType::AttackPercent(val.map(Percent::try_new).transpose()?)
+            }
+            EntityType::Damage(val) => {
+                EntityType::DamagePercent(val.map(Percent::try_new).transpose()?)
+            }
+            EntityType::Health(val) => {
+                EntityType::HealthPercent(val.map(Percent::try_new).transpose()?)
+            }
+            EntityType::Gold(val) => {
+                EntityType::GoldPercent(val.map(Percent::try_new).transpose()?)
+            }
+            EntityType::Trumpet(val) => {
+                EntityType::TrumpetPercent(val.map(Percent::try_new).transpose()?)
+            }
             _ => bail!("{self:?} doesn't have a EntityType 'percent' variant."),
         })
     }
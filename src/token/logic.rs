@@ -1,14 +1,12 @@
 //! SAP logic.
 
-use std::str::FromStr;
-
-use anyhow::bail;
+use std::fmt::Display;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Logic related tokens.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum LogicType {
     /// If a condition.
@@ -38,11 +36,22 @@ pub enum LogicType {
     Then,
     /// Until something.
     Until,
+    /// Next of something.
+    /// - ex. `next battle`
+    Next,
+    /// Permanently.
+    Permanent,
+    /// Costs no gold.
+    /// - ex. `Stock a free Apple.`
+    Free,
     /// With some item.
     /// - ex. `Deer with Chili`
     With,
     /// Number of times an effect works.
     Works,
+    /// Number of times an effect triggers.
+    /// - ex. `Triggers 3 times.`
+    Triggers,
     /// Condition of having something.
     Have,
     /// Part of [`LogicType::ForEach`]
@@ -61,34 +70,49 @@ pub enum LogicType {
     In,
     /// Outside of battle.
     Outside,
+    /// Negation of a condition or entity.
+    /// - ex. `If this has no perk, ...`
+    /// - ex. `non-Faint pets`
+    Not,
 }
 
-impl FromStr for LogicType {
-    type Err = anyhow::Error;
+token_vocab! {
+    LogicType {
+        "if" => LogicType::If,
+        "and" => LogicType::And,
+        "then" => LogicType::Then,
+        "until" => LogicType::Until,
+        "next" => LogicType::Next,
+        "permanent" | "permanently" => LogicType::Permanent,
+        "free" => LogicType::Free,
+        "or" => LogicType::Or,
+        "start" => LogicType::Start,
+        "end" => LogicType::End,
+        "with" => LogicType::With,
+        "for" => LogicType::For,
+        "is" => LogicType::Is,
+        "has" | "have" => LogicType::Have,
+        "each" | "every" => LogicType::Each,
+        "for each" => LogicType::ForEach,
+        "before" => LogicType::Before,
+        "after" => LogicType::After,
+        "works" => LogicType::Works,
+        "triggers" => LogicType::Triggers,
+        "except" => LogicType::Except,
+        "in" => LogicType::In,
+        "to" => LogicType::To,
+        "outside" => LogicType::Outside,
+        "not" | "no" | "non" | "without" | "didn't" | "don't" | "doesn't" | "isn't" => {
+            LogicType::Not
+        },
+    }
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "if" => LogicType::If,
-            "and" => LogicType::And,
-            "then" => LogicType::Then,
-            "until" => LogicType::Until,
-            "or" => LogicType::Or,
-            "start" => LogicType::Start,
-            "end" => LogicType::End,
-            "with" => LogicType::With,
-            "for" => LogicType::For,
-            "is" => LogicType::Is,
-            "has" | "have" => LogicType::Have,
-            "each" | "every" => LogicType::Each,
-            "for each" => LogicType::ForEach,
-            "before" => LogicType::Before,
-            "after" => LogicType::After,
-            "works" => LogicType::Works,
-            "except" => LogicType::Except,
-            "in" => LogicType::In,
-            "to" => LogicType::To,
-            "outside" => LogicType::Outside,
-            _ => bail!("Not a valid LogicType. {s}"),
-        })
+impl Display for LogicType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.word() {
+            Some(word) => f.write_str(word),
+            None => write!(f, "{self:?}"),
+        }
     }
 }
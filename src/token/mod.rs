@@ -7,10 +7,51 @@ use serde::{Deserialize, Serialize};
 
 use crate::scanner::Scanner;
 
+/// Macro to define the words of a token type and parse the token type from them.
+/// * Each word is listed once so `VOCAB` and [`FromStr`](std::str::FromStr) can't differ.
+/// - ex. `"enemy" | "enemies" => TargetType::Enemy`
+macro_rules! token_vocab {
+    (
+        $(#[$meta:meta])*
+        $ty:ident $(<$lt:lifetime>)? {
+            $($first:literal $(| $word:literal)* => $variant:expr),+ $(,)?
+        }
+    ) => {
+        impl $(<$lt>)? $ty $(<$lt>)? {
+            #[doc = concat!("Words parsed into a [`", stringify!($ty), "`].")]
+            pub const VOCAB: &'static [&'static str] = &[$($first $(, $word)*),+];
+
+            #[doc = concat!("First word parsed into this [`", stringify!($ty), "`], if any.")]
+            /// - ex. `enemy` for both `enemy` and `enemies`
+            pub fn word(&self) -> Option<&'static str> {
+                $(
+                    if *self == $variant {
+                        return Some($first);
+                    }
+                )+
+                None
+            }
+        }
+
+        $(#[$meta])*
+        impl $(<$lt>)? std::str::FromStr for $ty $(<$lt>)? {
+            type Err = anyhow::Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(match s {
+                    $($first $(| $word)* => $variant,)+
+                    _ => anyhow::bail!("Not a valid {}. {s}", stringify!($ty)),
+                })
+            }
+        }
+    };
+}
+
 pub mod actions;
 pub mod attribute;
 pub mod logic;
 pub mod numeric;
+pub mod percent;
 pub mod position;
 pub mod target;
 pub mod types;
@@ -19,11 +60,11 @@ use self::types::TokenType;
 
 pub use self::{
     actions::ActionType, attribute::EntityType, logic::LogicType, numeric::NumericType,
-    position::PositionType, target::TargetType,
+    percent::Percent, position::PositionType, target::TargetType,
 };
 
 /// A SAP text token.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Token<'src> {
     /// Type of token.
@@ -40,6 +81,24 @@ impl<'src> Display for Token<'src> {
     }
 }
 
+impl<'src> Token<'src> {
+    /// Check if token carries no meaning by itself.
+    /// - ex. `is`, `then`, `to`, `with`, `a`
+    /// * End of text is filler.
+    /// * Items held `with` a pet are tokenized as entities so `with` is filler.
+    /// * Articles only count items in shop effects so are otherwise filler.
+    pub fn is_filler(&self) -> bool {
+        matches!(
+            self.ttype,
+            TokenType::EndText
+                | TokenType::Numeric(NumericType::Article)
+                | TokenType::Logic(
+                    LogicType::Is | LogicType::Then | LogicType::To | LogicType::With
+                )
+        )
+    }
+}
+
 /// Parse number.
 pub(crate) trait ParseNumber {
     /// Parsed numeric string and modify [`Self`] with it.
@@ -49,7 +108,7 @@ pub(crate) trait ParseNumber {
 }
 
 /// Wrapper for [`Vec<Token>`].
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct SAPTokens<'src>(pub Vec<Token<'src>>);
 
 impl<'src> Deref for SAPTokens<'src> {
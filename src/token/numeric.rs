@@ -1,31 +1,32 @@
 //! SAP numerical operations/values.
 
-use std::str::FromStr;
-
-use anyhow::bail;
-
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use super::ParseNumber;
+use super::{percent::Percent, ParseNumber};
 
 /// Numerical operation and value tokens.
 /// - A [`None`] indicates the word itself.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NumericType {
     /// A number.
     /// - ex. `two pets`
     Number(Option<i32>),
+    /// An article counting one item.
+    /// - ex. `a free Apple`
+    Article,
     /// A multiplier
     /// - ex. `double damage`
     Multiplier(Option<i32>),
     /// A percent.
     /// - ex. `300% of attack damage`
-    Percent(Option<f32>),
+    Percent(Option<Percent>),
     /// Plus operator.
+    /// - ex. `3 extra damage`
     Plus,
     /// Minus operator.
+    /// - ex. `2 less damage`
     Minus,
     /// Less than or equal.
     LessEqual,
@@ -41,30 +42,31 @@ pub enum NumericType {
     Min,
 }
 
-/// Coerces solely string numeric type.
-/// ex. one, double, etc.
-impl FromStr for NumericType {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "time" | "times" => NumericType::Multiplier(None),
-            "one" => NumericType::Number(Some(1)),
-            "two" => NumericType::Number(Some(2)),
-            "three" => NumericType::Number(Some(3)),
-            "four" => NumericType::Number(Some(4)),
-            "five" => NumericType::Number(Some(5)),
-            "six" => NumericType::Number(Some(6)),
-            "seven" => NumericType::Number(Some(7)),
-            "double" => NumericType::Multiplier(Some(2)),
-            "triple" => NumericType::Multiplier(Some(3)),
-            "lower" => NumericType::LessEqual,
-            "equal" => NumericType::Equal,
-            "greater" => NumericType::GreaterEqual,
-            "most" => NumericType::Max,
-            "least" => NumericType::Min,
-            _ => bail!("Not a valid numeric type."),
-        })
+token_vocab! {
+    /// Coerces solely string numeric type.
+    /// ex. one, double, etc.
+    NumericType {
+        "time" | "times" => NumericType::Multiplier(None),
+        "a" | "an" => NumericType::Article,
+        "one" => NumericType::Number(Some(1)),
+        "two" => NumericType::Number(Some(2)),
+        "three" => NumericType::Number(Some(3)),
+        "four" => NumericType::Number(Some(4)),
+        "five" => NumericType::Number(Some(5)),
+        "six" => NumericType::Number(Some(6)),
+        "seven" => NumericType::Number(Some(7)),
+        "once" => NumericType::Multiplier(Some(1)),
+        "twice" => NumericType::Multiplier(Some(2)),
+        "double" => NumericType::Multiplier(Some(2)),
+        "triple" => NumericType::Multiplier(Some(3)),
+        "lower" => NumericType::LessEqual,
+        "equal" => NumericType::Equal,
+        "greater" | "higher" => NumericType::GreaterEqual,
+        "sum" => NumericType::Sum,
+        "most" => NumericType::Max,
+        "least" => NumericType::Min,
+        "extra" => NumericType::Plus,
+        "less" => NumericType::Minus,
     }
 }
 
@@ -80,7 +82,8 @@ impl ParseNumber for NumericType {
             NumericType::Percent(ref mut v) => {
                 v.replace(num_str.parse()?);
             }
-            NumericType::Sum
+            NumericType::Article
+            | NumericType::Sum
             | NumericType::Plus
             | NumericType::Minus
             | NumericType::LessEqual
@@ -0,0 +1,141 @@
+//! SAP exact percentages.
+
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::bail;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Exact percentage in basis points, hundredths of a percent.
+/// - ex. `50%` -> `Percent::new(50)`
+/// - ex. `12.5%` -> `Percent::from_basis_points(1250)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Percent(i32);
+
+impl Percent {
+    /// Basis points in one percent.
+    const BASIS_POINTS: i32 = 100;
+
+    /// Create a percentage from a whole percent.
+    /// * Saturates at the bounds of [`i32`] basis points. See [`Percent::try_new`] to reject these.
+    /// - ex. `Percent::new(50)` -> `50%`
+    pub const fn new(percent: i32) -> Self {
+        Percent(percent.saturating_mul(Percent::BASIS_POINTS))
+    }
+
+    /// Create a percentage from a whole percent.
+    ///
+    /// ### Returns
+    /// * [`Percent`] or error if too large for [`i32`] basis points.
+    pub fn try_new(percent: i32) -> anyhow::Result<Self> {
+        let Some(basis_points) = percent.checked_mul(Percent::BASIS_POINTS) else {
+            bail!("Not a valid percent {percent}. Too large.")
+        };
+        Ok(Percent(basis_points))
+    }
+
+    /// Create a percentage from basis points.
+    /// - ex. `Percent::from_basis_points(1250)` -> `12.5%`
+    pub const fn from_basis_points(basis_points: i32) -> Self {
+        Percent(basis_points)
+    }
+
+    /// Percentage in basis points.
+    pub const fn basis_points(&self) -> i32 {
+        self.0
+    }
+
+    /// Whole percent, truncating any fraction.
+    pub const fn whole(&self) -> i32 {
+        self.0 / Percent::BASIS_POINTS
+    }
+}
+
+impl FromStr for Percent {
+    type Err = anyhow::Error;
+
+    /// Parse a decimal percentage with at most two decimal places.
+    /// - ex. `12.5` -> `Percent::from_basis_points(1250)`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (whole, fraction) = s.split_once('.').unwrap_or((s, ""));
+        if fraction.len() > 2 || !fraction.chars().all(|chr| chr.is_ascii_digit()) {
+            bail!("Not a valid percent {s}. Must have at most two decimal places.")
+        }
+        let whole: i32 = whole.parse()?;
+        let fraction: i32 = format!("{fraction:0<2}").parse()?;
+        let fraction = if s.starts_with('-') {
+            -fraction
+        } else {
+            fraction
+        };
+        let Some(basis_points) = whole
+            .checked_mul(Percent::BASIS_POINTS)
+            .and_then(|basis_points| basis_points.checked_add(fraction))
+        else {
+            bail!("Not a valid percent {s}. Too large.")
+        };
+        Ok(Percent(basis_points))
+    }
+}
+
+impl Display for Percent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let fraction = (self.0 % Percent::BASIS_POINTS).abs();
+        if fraction == 0 {
+            write!(f, "{}%", self.whole())
+        } else {
+            let sign = if self.0 < 0 && self.whole() == 0 {
+                "-"
+            } else {
+                ""
+            };
+            let fraction = format!("{fraction:02}");
+            write!(
+                f,
+                "{sign}{}.{}%",
+                self.whole(),
+                fraction.trim_end_matches('0')
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::Percent;
+
+    #[test]
+    fn test_parse_percent() {
+        assert_eq!(Percent::from_str("50").unwrap(), Percent::new(50));
+        assert_eq!(
+            Percent::from_str("12.5").unwrap(),
+            Percent::from_basis_points(1250)
+        );
+        assert_eq!(
+            Percent::from_str("0.25").unwrap(),
+            Percent::from_basis_points(25)
+        );
+        assert_eq!(
+            Percent::from_str("-0.5").unwrap(),
+            Percent::from_basis_points(-50)
+        );
+        assert!(Percent::from_str("33.333").is_err());
+        assert!(Percent::from_str("ten").is_err());
+        assert!(Percent::from_str("30000000").is_err());
+        assert!(Percent::try_new(30000000).is_err());
+        assert!(Percent::try_new(-30000000).is_err());
+        assert_eq!(Percent::try_new(50).unwrap(), Percent::new(50));
+    }
+
+    #[test]
+    fn test_display_percent() {
+        assert_eq!(Percent::new(50).to_string(), "50%");
+        assert_eq!(Percent::from_basis_points(1250).to_string(), "12.5%");
+        assert_eq!(Percent::from_basis_points(-50).to_string(), "-0.5%");
+        assert_eq!(Percent::from_basis_points(5).to_string(), "0.05%");
+    }
+}
@@ -1,13 +1,12 @@
 //! SAP item positions inside/outside of battle.
-use std::str::FromStr;
 
-use anyhow::bail;
+use std::fmt::Display;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// SAP item positions.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PositionType {
     /// This pet.
@@ -48,29 +47,49 @@ pub enum PositionType {
     Opposite,
 }
 
-impl FromStr for PositionType {
-    type Err = anyhow::Error;
+token_vocab! {
+    PositionType {
+        "this" | "itself" => PositionType::OnSelf,
+        "other" | "nonself" => PositionType::NonSelf,
+        "ahead" | "forward" => PositionType::Ahead,
+        "behind" => PositionType::Behind,
+        "adjacent" => PositionType::Adjacent,
+        "nearest" => PositionType::Nearest,
+        "all" => PositionType::All,
+        "random" | "any" => PositionType::Any,
+        "highest" => PositionType::Highest,
+        "lowest" => PositionType::Lowest,
+        "left-most" | "back" => PositionType::LeftMost,
+        "right-most" | "front" => PositionType::RightMost,
+        "directly back" | "whoever" | "it" | "its" => PositionType::Trigger,
+        "most healthy" => PositionType::Healthiest,
+        "strongest" => PositionType::Strongest,
+        "weakest" => PositionType::Weakest,
+        "opposite" => PositionType::Opposite,
+    }
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "this" | "itself" => PositionType::OnSelf,
-            "other" | "nonself" => PositionType::NonSelf,
-            "ahead" | "forward" => PositionType::Ahead,
-            "behind" => PositionType::Behind,
-            "adjacent" => PositionType::Adjacent,
-            "nearest" => PositionType::Nearest,
-            "all" => PositionType::All,
-            "random" | "any" => PositionType::Any,
-            "highest" => PositionType::Highest,
-            "lowest" => PositionType::Lowest,
-            "left-most" => PositionType::LeftMost,
-            "right-most" | "front" => PositionType::RightMost,
-            "directly back" | "whoever" | "it" | "its" => PositionType::Trigger,
-            "most healthy" => PositionType::Healthiest,
-            "strongest" => PositionType::Strongest,
-            "weakest" => PositionType::Weakest,
-            "opposite" => PositionType::Opposite,
-            _ => bail!("{s} not a valid PositionType"),
+impl Display for PositionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            PositionType::OnSelf => "this",
+            PositionType::NonSelf => "other",
+            PositionType::Ahead => "ahead",
+            PositionType::Behind => "behind",
+            PositionType::Nearest => "nearest",
+            PositionType::Adjacent => "adjacent",
+            PositionType::All => "all",
+            PositionType::Any => "random",
+            PositionType::Highest => "highest",
+            PositionType::Lowest => "lowest",
+            PositionType::LeftMost => "left-most",
+            PositionType::RightMost => "right-most",
+            PositionType::Trigger => "triggering",
+            PositionType::Illest => "least healthy",
+            PositionType::Healthiest => "healthiest",
+            PositionType::Strongest => "strongest",
+            PositionType::Weakest => "weakest",
+            PositionType::Opposite => "opposite",
         })
     }
 }
@@ -1,14 +1,12 @@
 //! SAP effect targets.
 
-use std::str::FromStr;
-
-use anyhow::bail;
+use std::fmt::Display;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// SAP target types.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TargetType {
     /// Friendly pets.
@@ -20,15 +18,20 @@ pub enum TargetType {
     Shop,
 }
 
-impl FromStr for TargetType {
-    type Err = anyhow::Error;
+token_vocab! {
+    TargetType {
+        "enemy" | "enemies" | "opponent" => TargetType::Enemy,
+        "friend" | "friends" | "friendly" => TargetType::Friend,
+        "shop" => TargetType::Shop,
+    }
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "enemy" | "enemies" | "opponent" => TargetType::Enemy,
-            "friend" | "friends" | "friendly" => TargetType::Friend,
-            "shop" => TargetType::Shop,
-            _ => bail!("Not a valid numeric type."),
+impl Display for TargetType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            TargetType::Friend => "friend",
+            TargetType::Enemy => "enemy",
+            TargetType::Shop => "shop",
         })
     }
 }
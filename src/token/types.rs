@@ -12,13 +12,13 @@ use super::{
 };
 
 /// All possible SAP token types.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TokenType<'src> {
     /// Numeric tokens.
     Numeric(NumericType),
     /// Entity tokens.
-    #[serde(borrow)]
+    #[cfg_attr(feature = "serde", serde(borrow))]
     Entity(EntityType<'src>),
     /// End of text.
     EndText,
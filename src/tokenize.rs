@@ -52,6 +52,7 @@ impl<'src> SAPText<'src> {
     /// Tokenize text.
     /// - Any uppercase text is treated as an itemname unless it is at the start of the text.
     ///     - ex. `Gain Lemon.`
+    ///     - Known foods, perks, and ailments are named foods, perks, and ailments. See [`EntityType::FOODS`], [`EntityType::PERKS`], and [`EntityType::AILMENTS`].
     /// - Most punctuation is ignored.
     ///
     /// ```
@@ -83,7 +84,7 @@ impl<'src> SAPText<'src> {
     ///     ]
     /// )
     /// ````
-    pub fn tokenize(&'src self) -> anyhow::Result<SAPTokens<'src>> {
+    pub fn tokenize(&self) -> anyhow::Result<SAPTokens<'src>> {
         let mut tokens = vec![];
         let mut state = Scanner::default();
 
@@ -105,7 +106,7 @@ impl<'src> SAPText<'src> {
 
     /// Scans a character and if meets some conditions, consumes remaining characters to create zero or more tokens.
     fn scan_token(
-        &'src self,
+        &self,
         state: &mut Scanner,
         tokens: &mut Vec<Token<'src>>,
     ) -> anyhow::Result<Option<()>> {
@@ -142,7 +143,7 @@ impl<'src> SAPText<'src> {
 
     /// Replaces next alphabetic token if meets condition.
     fn add_multi_token_by_cond(
-        &'src self,
+        &self,
         state: &mut Scanner,
         prev_state: Option<&mut Scanner>,
         prev_ttype: Option<TokenType<'src>>,
@@ -184,7 +185,7 @@ impl<'src> SAPText<'src> {
 
     /// Scans any alphabetic token.
     fn scan_word_token(
-        &'src self,
+        &self,
         state: &mut Scanner,
         tokens: &mut Vec<Token<'src>>,
     ) -> anyhow::Result<()> {
@@ -196,7 +197,26 @@ impl<'src> SAPText<'src> {
             .filter(|chr| chr.is_ascii_uppercase() && prev_chr.is_some())
             .is_some();
 
-        while self.advance_by_cond(state, is_alpha).is_some() {}
+        // Consume word including hyphenated words.
+        // ex. left-most
+        loop {
+            while self.advance_by_cond(state, is_alpha).is_some() {}
+            if self.peek(state.current) == Some('-')
+                && is_alpha(self.peek(state.current + 1)).is_some()
+            {
+                // Negated word. Scan rest of word separately.
+                // ex. non-Faint
+                if self.get_lowercase_text(state)? == "non" {
+                    tokens.push(self.build_token(state, TokenType::Logic(LogicType::Not))?);
+                    state.move_cursor(true, 1).set_start_to_current();
+                    self.advance(state);
+                    return self.scan_word_token(state, tokens);
+                }
+                state.move_cursor(true, 1);
+            } else {
+                break;
+            }
+        }
 
         let next_chr = self.peek(state.current);
 
@@ -229,7 +249,7 @@ impl<'src> SAPText<'src> {
                         .map(|word| {
                             word.chars()
                                 .next()
-                                .map_or(false, |chr| chr.is_ascii_uppercase())
+                                .is_some_and(|chr| chr.is_ascii_uppercase())
                         })
                         .unwrap_or(false);
 
@@ -237,7 +257,8 @@ impl<'src> SAPText<'src> {
                     if !is_pet_attr {
                         is_pet_attr = next_word == Some("friend")
                             || next_word == Some("friends")
-                            || next_word == Some("pet");
+                            || next_word == Some("pet")
+                            || next_word == Some("pets");
                     }
 
                     // Perks have suffix word "perk"
@@ -262,9 +283,8 @@ impl<'src> SAPText<'src> {
                                 number: None,
                                 name: None,
                                 // Assign attribute if any.
-                                attr: is_pet_attr.then_some(
-                                    self.get_text_slice(state.start..prev_curr - 1, false)?,
-                                ),
+                                attr: is_pet_attr
+                                    .then_some(self.get_text_slice(state.start..prev_curr - 1)?),
                             });
                         }
                         // Hit unrelated word.
@@ -278,7 +298,7 @@ impl<'src> SAPText<'src> {
                         break;
                     }
                 }
-                let word = self.get_text_slice(start_of_word..state.current, false)?;
+                let word = self.get_text_slice(start_of_word..state.current)?;
 
                 let token = match entity {
                     Some(
@@ -291,20 +311,23 @@ impl<'src> SAPText<'src> {
                         }
                         state.start = start_of_word;
                         // Safe to unwrap as checked some entity.
-                        self.build_token(state, TokenType::Entity(entity.unwrap()))?
+                        let entity = entity.unwrap().into_known_item();
+                        self.build_token(state, TokenType::Entity(entity))?
                     }
                     _ => {
                         // Get lowercase effect for parsing.
                         let lowercase_word =
-                            self.get_text_slice(start_of_word..state.current, true)?;
+                            self.get_lowercase_text_slice(start_of_word..state.current)?;
                         // Try to parse word defaulting to assuming is pet name.
-                        let ttype = TokenType::parse(lowercase_word, None).unwrap_or(
-                            TokenType::Entity(EntityType::Pet {
-                                number: None,
-                                name: Some(word),
-                                attr: None,
-                            }),
-                        );
+                        let ttype =
+                            TokenType::parse(lowercase_word, None).unwrap_or(TokenType::Entity(
+                                EntityType::Pet {
+                                    number: None,
+                                    name: Some(word),
+                                    attr: None,
+                                }
+                                .into_known_item(),
+                            ));
                         self.build_token(state, ttype)?
                     }
                 };
@@ -313,7 +336,7 @@ impl<'src> SAPText<'src> {
             // Non-item name word token.
             // ex. attack
             (Some(' '), false) => {
-                let word = self.get_text(state, true)?;
+                let word = self.get_lowercase_text(state)?;
                 let ttype = TokenType::parse(word, None);
 
                 // Consume digits ahead to create numeric token, if anys.
@@ -357,6 +380,19 @@ impl<'src> SAPText<'src> {
                             tokens,
                         )?;
                     }
+                    // ex. "Stock a free Apple"
+                    // Articles only count items directly affected by an action. Ignore others.
+                    Ok(TokenType::Numeric(NumericType::Article)) => {
+                        if matches!(
+                            tokens.last().map(|token| &token.ttype),
+                            Some(TokenType::Action(_))
+                        ) {
+                            tokens.push(self.build_token(
+                                &prev_state,
+                                TokenType::Numeric(NumericType::Article),
+                            )?);
+                        }
+                    }
                     // Otherwise, add new token.
                     Ok(ttype) => {
                         tokens.push(self.build_token(&prev_state, ttype)?);
@@ -374,28 +410,29 @@ impl<'src> SAPText<'src> {
             // Itemname at end of punctuation/statement.
             // ex. Dog with Chili.
             (Some(_), true) | (None, true) => {
-                let word = self.get_text(state, false).ok();
+                let word = self.get_text(state).ok();
                 // If LogicType::With prev token type, assume food.
-                let ttype = if matches!(
+                let entity = if matches!(
                     tokens.last().map(|t| &t.ttype),
                     Some(TokenType::Logic(LogicType::With))
                 ) {
-                    TokenType::Entity(EntityType::Food {
+                    EntityType::Food {
                         number: None,
                         name: word,
-                    })
+                    }
                 } else {
-                    TokenType::Entity(EntityType::Pet {
+                    EntityType::Pet {
                         number: None,
                         name: word,
                         attr: None,
-                    })
+                    }
                 };
+                let ttype = TokenType::Entity(entity.into_known_item());
                 tokens.push(self.build_token(state, ttype)?)
             }
             // Any non-itemname word token.
             (Some(_), false) | (None, false) => {
-                let word = self.get_text(state, true)?;
+                let word = self.get_lowercase_text(state)?;
                 if let Ok(ttype) = TokenType::parse(word, None) {
                     tokens.push(self.build_token(state, ttype)?);
                 }
@@ -407,7 +444,7 @@ impl<'src> SAPText<'src> {
 
     /// Scans numeric tokens that being with a `+` or `-`.
     fn scan_sign_token(
-        &'src self,
+        &self,
         state: &mut Scanner,
         tokens: &mut Vec<Token<'src>>,
     ) -> anyhow::Result<()> {
@@ -457,7 +494,7 @@ impl<'src> SAPText<'src> {
 
     /// Scans numeric tokens starting with a digit.
     fn scan_numeric_token(
-        &'src self,
+        &self,
         state: &mut Scanner,
         tokens: &mut Vec<Token<'src>>,
     ) -> anyhow::Result<()> {
@@ -472,7 +509,7 @@ impl<'src> SAPText<'src> {
                 tokens.push(self.build_token(
                     state,
                     TokenType::Entity(EntityType::Attack(Some(
-                        self.get_text(&num_literal_state, false)?.parse()?,
+                        self.get_text(&num_literal_state)?.parse()?,
                     ))),
                 )?);
 
@@ -488,11 +525,17 @@ impl<'src> SAPText<'src> {
             // ex. 1 attack
             // ex. 1-gold
             Some(' ') | Some('-') | Some('%') => {
+                let is_perc_token = next_char.as_ref().is_some_and(|chr| *chr == '%');
+                let num_literal = self.get_text(&num_literal_state)?;
                 let num_literal_token = self.build_token(
                     &num_literal_state,
-                    TokenType::Numeric(NumericType::Number(Some(
-                        self.get_text(&num_literal_state, false)?.parse()?,
-                    ))),
+                    // Percent of something.
+                    // ex. 50% of
+                    TokenType::Numeric(if is_perc_token {
+                        NumericType::Percent(Some(num_literal.parse()?))
+                    } else {
+                        NumericType::Number(Some(num_literal.parse()?))
+                    }),
                 )?;
                 // Adjust cursor based on next char.
                 let cur_adj = match next_char.map(|chr| chr.is_whitespace() || chr == '-') {
@@ -502,9 +545,11 @@ impl<'src> SAPText<'src> {
                 let Some(mut next_token) =
                     self.consume_while_cond(state, Some(num_literal_state), cur_adj, is_alpha)
                 else {
+                    // Unknown word so just add number.
+                    // ex. 3 times
+                    tokens.push(num_literal_token);
                     return Ok(());
                 };
-                let is_perc_token = next_char.as_ref().map_or(false, |chr| *chr == '%');
                 match (&mut next_token.ttype, is_perc_token) {
                     // Try to turn into percent variant if percent next token.
                     (TokenType::Entity(attr_type), true) => {
@@ -512,16 +557,49 @@ impl<'src> SAPText<'src> {
                         tokens.push(next_token);
                     }
                     // Only add num attr token if next token related to entities.
-                    (TokenType::Numeric(_) | TokenType::Entity(_), _) => tokens.push(next_token),
-                    // Otherwise, just add number token.
-                    _ => tokens.push(num_literal_token),
+                    // Signs are kept separate from their number.
+                    (
+                        TokenType::Numeric(
+                            NumericType::Number(_)
+                            | NumericType::Multiplier(_)
+                            | NumericType::Percent(_)
+                            | NumericType::LessEqual
+                            | NumericType::Equal
+                            | NumericType::GreaterEqual
+                            | NumericType::Sum
+                            | NumericType::Max
+                            | NumericType::Min,
+                        )
+                        | TokenType::Entity(_),
+                        _,
+                    ) => tokens.push(next_token),
+                    // Otherwise, add number token and next token without number.
+                    // ex. 3 friends
+                    // ex. 2 less damage
+                    _ => {
+                        next_token.metadata.start =
+                            num_literal_token.metadata.current + cur_adj as usize;
+                        tokens.push(num_literal_token);
+                        next_token.text = self.get_text(&next_token.metadata)?;
+                        tokens.push(next_token);
+                    }
                 }
             }
+            // ex. 2x
+            Some('x') if is_alpha(self.peek(state.current + 1)).is_none() => {
+                state.move_cursor(true, 1);
+                tokens.push(self.build_token(
+                    state,
+                    TokenType::Numeric(NumericType::Multiplier(Some(
+                        self.get_text(&num_literal_state)?.parse()?,
+                    ))),
+                )?)
+            }
             // Ignore everything else and just add number.
             Some(_) | None => tokens.push(self.build_token(
                 state,
                 TokenType::Numeric(NumericType::Number(Some(
-                    self.get_text(&num_literal_state, false)?.parse()?,
+                    self.get_text(&num_literal_state)?.parse()?,
                 ))),
             )?),
         }
@@ -555,7 +633,7 @@ impl<'src> SAPText<'src> {
     /// ### Returns
     /// * [`Token`]
     fn consume_while_cond(
-        &'src self,
+        &self,
         state: &mut Scanner,
         literal_state: Option<Scanner>,
         cur_adj: isize,
@@ -566,16 +644,14 @@ impl<'src> SAPText<'src> {
         // Move cursor while condition is met.
         while self.advance_by_cond(state, &cond).is_some() {}
 
-        let Ok(word) = self.get_text(state, true) else {
+        let Ok(word) = self.get_lowercase_text(state) else {
             return None;
         };
         if let Some(mut updated_literal_state) = literal_state {
-            let literal_value = self.get_text(&updated_literal_state, false).ok();
+            let literal_value = self.get_text(&updated_literal_state).ok();
             // Use literal state updated so Token text includes both literal value and attribute token.
             updated_literal_state.current = state.current;
-            let Some(ttype) = TokenType::parse(word, literal_value).ok() else {
-                return None;
-            };
+            let ttype = TokenType::parse(word, literal_value).ok()?;
             self.build_token(&updated_literal_state, ttype).ok()
         } else {
             let Ok(ttype) = TokenType::parse(word, None) else {
@@ -585,42 +661,52 @@ impl<'src> SAPText<'src> {
         }
     }
 
-    /// Gets text slice but with [`Scanner`].
-    /// * TODO: Combine with [`SAPText::get_text_slice`].
-    fn get_text(&'src self, state: &Scanner, lowercase: bool) -> anyhow::Result<&'src str> {
-        let source = if lowercase {
-            &self.lowercase_effect
-        } else {
-            self.effect
-        };
-        source.get(state.start..state.current).with_context(|| {
-            format!(
-                "Invalid start {} and current {} indices in source.",
-                state.start, state.current
-            )
-        })
+    /// Gets raw text slice but with [`Scanner`].
+    /// * The returned slice borrows from the source text rather than [`SAPText`].
+    fn get_text(&self, state: &Scanner) -> anyhow::Result<&'src str> {
+        self.get_text_slice(state.start..state.current)
+    }
+
+    /// Gets lowercase text slice but with [`Scanner`].
+    fn get_lowercase_text(&self, state: &Scanner) -> anyhow::Result<&str> {
+        self.get_lowercase_text_slice(state.start..state.current)
     }
 
-    /// Gets text slice.
+    /// Gets raw text slice.
     ///
     /// ### Params
     /// * `i`
     ///     * Generic with [`SliceIndex<str>`].
     ///     * Passed to [`str::get`].
-    /// * `lowercase`
     ///
     /// ### Returns
     /// * Slice of source text.
-    fn get_text_slice<I>(&'src self, i: I, lowercase: bool) -> anyhow::Result<&I::Output>
+    fn get_text_slice<I>(&self, i: I) -> anyhow::Result<&'src I::Output>
     where
         I: SliceIndex<str>,
     {
-        let source = if lowercase {
-            &self.lowercase_effect
-        } else {
-            self.effect
-        };
-        source.get(i).context("Invalid indices in source text.")
+        self.effect
+            .get(i)
+            .context("Invalid indices in source text.")
+    }
+
+    /// Gets lowercase text slice.
+    /// * Only used for parsing as slice is tied to the lifetime of [`SAPText`].
+    ///
+    /// ### Params
+    /// * `i`
+    ///     * Generic with [`SliceIndex<str>`].
+    ///     * Passed to [`str::get`].
+    ///
+    /// ### Returns
+    /// * Slice of lowercase source text.
+    fn get_lowercase_text_slice<I>(&self, i: I) -> anyhow::Result<&I::Output>
+    where
+        I: SliceIndex<str>,
+    {
+        self.lowercase_effect
+            .get(i)
+            .context("Invalid indices in lowercase source text.")
     }
 
     /// Build a token.
@@ -633,10 +719,10 @@ impl<'src> SAPText<'src> {
     ///
     /// ### Returns
     /// * New [`Token`],
-    fn build_token(&'src self, state: &Scanner, ttype: TokenType<'src>) -> anyhow::Result<Token> {
+    fn build_token(&self, state: &Scanner, ttype: TokenType<'src>) -> anyhow::Result<Token<'src>> {
         Ok(Token {
             ttype,
-            text: self.get_text(state, false)?,
+            text: self.get_text(state)?,
             metadata: state.clone(),
         })
     }
@@ -678,7 +764,7 @@ impl<'src> SAPText<'src> {
 
 #[cfg(test)]
 mod test {
-    use crate::token::actions::ActionType;
+    use crate::token::{actions::ActionType, percent::Percent};
     use pretty_assertions::assert_eq;
 
     use super::*;
@@ -770,7 +856,7 @@ mod test {
                     }
                 },
                 Token {
-                    ttype: TokenType::Entity(EntityType::Food {
+                    ttype: TokenType::Entity(EntityType::Perk {
                         number: None,
                         name: Some("Fortune Cookie Perk")
                     }),
@@ -896,7 +982,7 @@ mod test {
                     }
                 },
                 Token {
-                    ttype: TokenType::Entity(EntityType::Food {
+                    ttype: TokenType::Entity(EntityType::Perk {
                         number: None,
                         name: Some("Chili")
                     }),
@@ -985,7 +1071,7 @@ mod test {
             *tokens,
             vec![
                 Token {
-                    ttype: TokenType::Entity(EntityType::HealthPercent(Some(100.0))),
+                    ttype: TokenType::Entity(EntityType::HealthPercent(Some(Percent::new(100)))),
                     text: "+100% health",
                     metadata: Scanner {
                         start: 0,
@@ -1003,7 +1089,7 @@ mod test {
                     }
                 },
                 Token {
-                    ttype: TokenType::Entity(EntityType::AttackPercent(Some(120.0))),
+                    ttype: TokenType::Entity(EntityType::AttackPercent(Some(Percent::new(120)))),
                     text: "+120% attack",
                     metadata: Scanner {
                         start: 17,
@@ -1021,7 +1107,11 @@ mod test {
                     }
                 }
             ]
-        )
+        );
+
+        // Signed percent too large for basis points.
+        assert!(SAPText::new("+30000000% attack").tokenize().is_err());
+        assert!(SAPText::new("-30000000% attack").tokenize().is_err());
     }
 
     #[test]
@@ -1097,4 +1187,99 @@ mod test {
             ]
         )
     }
+
+    #[test]
+    fn test_tokenize_numeric_multiplier_and_percent() {
+        let txt = SAPText::new("2x 50% of");
+        let tokens = txt.tokenize().unwrap();
+
+        assert_eq!(
+            *tokens,
+            vec![
+                Token {
+                    ttype: TokenType::Numeric(NumericType::Multiplier(Some(2))),
+                    text: "2x",
+                    metadata: Scanner {
+                        start: 0,
+                        current: 2,
+                        line: 1
+                    }
+                },
+                Token {
+                    ttype: TokenType::Numeric(NumericType::Percent(Some(Percent::new(50)))),
+                    text: "50",
+                    metadata: Scanner {
+                        start: 3,
+                        current: 5,
+                        line: 1
+                    }
+                },
+                Token {
+                    ttype: TokenType::EndText,
+                    text: "",
+                    metadata: Scanner {
+                        start: 9,
+                        current: 9,
+                        line: 1
+                    }
+                }
+            ]
+        );
+
+        // Percent too large for basis points.
+        let txt = SAPText::new("Deal 30000000% attack damage to one enemy.");
+        assert!(txt.tokenize().is_err());
+    }
+
+    #[test]
+    fn test_tokenize_named_perk_and_ailment() {
+        let tokens = SAPText::new("Give the friend behind Melon.")
+            .tokenize()
+            .unwrap();
+        assert_eq!(
+            tokens[3].ttype,
+            TokenType::Entity(EntityType::Perk {
+                number: None,
+                name: Some("Melon")
+            })
+        );
+        let tokens = SAPText::new("Make the enemy Weak.").tokenize().unwrap();
+        assert_eq!(
+            tokens[2].ttype,
+            TokenType::Entity(EntityType::Ailment {
+                number: None,
+                name: Some("Weak")
+            })
+        );
+    }
+
+    #[test]
+    fn test_tokenize_negation() {
+        let txt = SAPText::new("Give non-Faint pets +1 attack.");
+        let tokens = txt.tokenize().unwrap();
+        assert_eq!(
+            tokens[1],
+            Token {
+                ttype: TokenType::Logic(LogicType::Not),
+                text: "non",
+                metadata: Scanner {
+                    start: 5,
+                    current: 8,
+                    line: 1
+                }
+            }
+        );
+        assert_eq!(
+            tokens[2].ttype,
+            TokenType::Entity(EntityType::Pet {
+                number: None,
+                name: None,
+                attr: Some("Faint")
+            })
+        );
+
+        let txt = SAPText::new("If you didn't lose last battle, gain 1 gold.");
+        let tokens = txt.tokenize().unwrap();
+        assert_eq!(tokens[1].ttype, TokenType::Logic(LogicType::Not));
+    }
 }
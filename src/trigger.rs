@@ -1,3 +1,5 @@
+use std::fmt::Display;
+
 use anyhow::bail;
 
 #[cfg(feature = "serde")]
@@ -10,15 +12,18 @@ use crate::token::{
 
 /// A Super Auto Pets effect trigger.
 /// - ex. `End turn`
-#[derive(Debug, Clone, Default, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EffectTrigger<'src> {
     /// Action
     pub action: Option<ActionType>,
     /// Number of trigger, if any.
     pub number: Option<usize>,
+    /// How occurrences of trigger are counted.
+    /// - ex. `Every 3 friends summoned`
+    pub count: TriggerCount,
     /// Entity type.
-    #[serde(borrow)]
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub entity: Option<EntityType<'src>>,
     /// The target type.
     pub target: Option<TargetType>,
@@ -30,6 +35,285 @@ pub struct EffectTrigger<'src> {
     pub sec_pos: Option<PositionType>,
 }
 
+/// How occurrences of an [`EffectTrigger`] are counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TriggerCount {
+    /// Triggers on each occurrence.
+    /// - ex. `Friend summoned`
+    #[default]
+    Once,
+    /// Triggers on every nth occurrence.
+    /// - ex. `Every 3 friends summoned`
+    EveryN(usize),
+    /// Triggers after n occurrences.
+    /// - ex. `After 2 hurts`
+    AfterN(usize),
+    /// Triggers when n occurrences happen at once.
+    /// - ex. `Two friends faint`
+    Simultaneous(usize),
+}
+
+/// Macro to define [`TriggerKind`] variants and their equivalent [`EffectTrigger`].
+macro_rules! trigger_kinds {
+    ($($(#[$doc:meta])* $kind:ident => { $($field:ident: $value:expr),* $(,)? }),+ $(,)?) => {
+        /// Canonical kind of [`EffectTrigger`].
+        /// - ex. `Start of battle` -> [`TriggerKind::StartOfBattle`]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        pub enum TriggerKind {
+            $($(#[$doc])* $kind),+
+        }
+
+        impl TriggerKind {
+            /// All trigger kinds.
+            pub const ALL: &'static [TriggerKind] = &[$(TriggerKind::$kind),+];
+        }
+
+        impl<'src> From<TriggerKind> for EffectTrigger<'src> {
+            fn from(kind: TriggerKind) -> Self {
+                match kind {
+                    $(TriggerKind::$kind => EffectTrigger {
+                        $($field: Some($value),)*
+                        ..Default::default()
+                    }),+
+                }
+            }
+        }
+    };
+}
+
+trigger_kinds! {
+    /// - ex. `Start of battle`
+    StartOfBattle => { entity: EntityType::Battle(None), logic: LogicType::Start },
+    /// - ex. `Start of turn`
+    StartOfTurn => { entity: EntityType::Turn(None), logic: LogicType::Start },
+    /// - ex. `End turn`
+    EndTurn => { entity: EntityType::Turn(None), logic: LogicType::End },
+    /// - ex. `Before attack`
+    BeforeAttack => { entity: EntityType::Attack(None), logic: LogicType::Before },
+    /// - ex. `After attack`
+    AfterAttack => { entity: EntityType::Attack(None), logic: LogicType::After },
+    /// - ex. `Friend ahead attacks`
+    FriendAheadAttacks => {
+        action: ActionType::Attack,
+        target: TargetType::Friend,
+        prim_pos: PositionType::Ahead,
+    },
+    /// - ex. `Faint`
+    Faint => { action: ActionType::Faint },
+    /// - ex. `Before faint`
+    BeforeFaint => { action: ActionType::Faint, logic: LogicType::Before },
+    /// - ex. `Friend faints`
+    FriendFaints => { action: ActionType::Faint, target: TargetType::Friend },
+    /// - ex. `Friend ahead faints`
+    FriendAheadFaints => {
+        action: ActionType::Faint,
+        target: TargetType::Friend,
+        prim_pos: PositionType::Ahead,
+    },
+    /// - ex. `Enemy faints`
+    EnemyFaints => { action: ActionType::Faint, target: TargetType::Enemy },
+    /// - ex. `Hurt`
+    Hurt => { action: ActionType::Hurt },
+    /// - ex. `Friend hurt`
+    FriendHurt => { action: ActionType::Hurt, target: TargetType::Friend },
+    /// - ex. `Friend ahead hurt`
+    FriendAheadHurt => {
+        action: ActionType::Hurt,
+        target: TargetType::Friend,
+        prim_pos: PositionType::Ahead,
+    },
+    /// - ex. `Enemy hurt`
+    EnemyHurt => { action: ActionType::Hurt, target: TargetType::Enemy },
+    /// - ex. `Summoned`
+    Summoned => { action: ActionType::Summon },
+    /// - ex. `Friend summoned`
+    FriendSummoned => { action: ActionType::Summon, target: TargetType::Friend },
+    /// - ex. `Enemy summoned`
+    EnemySummoned => { action: ActionType::Summon, target: TargetType::Enemy },
+    /// - ex. `Knock out`
+    KnockOut => { action: ActionType::Knock },
+    /// - ex. `Level-up`
+    LevelUp => { action: ActionType::LevelUp },
+    /// - ex. `Friend level-up`
+    FriendLevelUp => { action: ActionType::LevelUp, target: TargetType::Friend },
+    /// - ex. `Sell`
+    Sell => { action: ActionType::Sell, target: TargetType::Shop },
+    /// - ex. `Friend sold`
+    FriendSold => { action: ActionType::Sell, target: TargetType::Friend },
+    /// - ex. `Buy`
+    Buy => { action: ActionType::Buy, target: TargetType::Shop },
+    /// - ex. `Friend bought`
+    FriendBought => { action: ActionType::Buy, target: TargetType::Friend },
+    /// - ex. `Buy food`
+    BuyFood => {
+        action: ActionType::Buy,
+        entity: EntityType::Food { number: None, name: None },
+        target: TargetType::Shop,
+    },
+    /// - ex. `Eats food`
+    EatsFood => {
+        action: ActionType::Eat,
+        entity: EntityType::Food { number: None, name: None },
+        target: TargetType::Shop,
+    },
+    /// - ex. `Roll`
+    Roll => { action: ActionType::Roll, target: TargetType::Shop },
+    /// - ex. `Empty front space`
+    EmptyFrontSpace => { entity: EntityType::Space(None), prim_pos: PositionType::RightMost },
+}
+
+impl Display for TriggerCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TriggerCount::Once => f.write_str("once"),
+            TriggerCount::EveryN(n) => write!(f, "every {n}"),
+            TriggerCount::AfterN(n) => write!(f, "after {n}"),
+            TriggerCount::Simultaneous(n) => write!(f, "{n} at once"),
+        }
+    }
+}
+
+impl<'src> Display for EffectTrigger<'src> {
+    /// Format trigger with its count first and its action last.
+    /// - ex. `friend ahead faint`, `start of battle`, `every 3 friend summon`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let is_after =
+            |pos: &PositionType| matches!(pos, PositionType::Ahead | PositionType::Behind);
+        let mut words: Vec<String> = vec![];
+        match self.count {
+            TriggerCount::Once => words.extend(self.number.map(|num| num.to_string())),
+            count => words.push(count.to_string()),
+        }
+        match self.logic {
+            Some(logic @ (LogicType::Start | LogicType::End)) => words.push(format!("{logic} of")),
+            logic => words.extend(logic.map(|logic| logic.to_string())),
+        }
+        words.extend(
+            self.prim_pos
+                .filter(|pos| !is_after(pos))
+                .map(|pos| pos.to_string()),
+        );
+        words.extend(self.target.map(|target| target.to_string()));
+        words.extend(self.prim_pos.filter(is_after).map(|pos| pos.to_string()));
+        words.extend(self.entity.as_ref().map(|entity| entity.to_string()));
+        words.extend(self.action.map(|action| action.to_string()));
+        words.extend(self.sec_pos.map(|pos| pos.to_string()));
+        f.write_str(&words.join(" "))
+    }
+}
+
+impl<'src> TryFrom<&EffectTrigger<'src>> for TriggerKind {
+    type Error = anyhow::Error;
+
+    /// Convert an uncounted trigger to its [`TriggerKind`].
+    /// * Counted triggers are an error since their count would be lost. See [`EffectTrigger::counted_kind`].
+    fn try_from(trigger: &EffectTrigger<'src>) -> Result<Self, Self::Error> {
+        if trigger.count != TriggerCount::Once {
+            bail!(
+                "Counted trigger ({:?}) has no kind without its count. {trigger:?}",
+                trigger.count
+            )
+        }
+        trigger.uncounted_kind()
+    }
+}
+
+impl<'src> EffectTrigger<'src> {
+    /// Get canonical [`TriggerKind`] of trigger.
+    /// * Only matches exactly so no part of trigger is lost.
+    /// * [`EffectTrigger::number`] and [`EffectTrigger::count`] are ignored.
+    fn uncounted_kind(&self) -> anyhow::Result<TriggerKind> {
+        let uncounted_trigger = EffectTrigger {
+            number: None,
+            count: TriggerCount::Once,
+            ..self.clone()
+        };
+        let Some(kind) = TriggerKind::ALL
+            .iter()
+            .find(|kind| EffectTrigger::from(**kind) == uncounted_trigger)
+        else {
+            bail!("Unrecognized trigger. {self:?}")
+        };
+        Ok(*kind)
+    }
+
+    /// Get canonical [`TriggerKind`] of trigger.
+    /// * Counted triggers are an error. See [`EffectTrigger::counted_kind`].
+    ///
+    /// ```
+    /// use saplex::{SAPText, EffectTrigger, trigger::TriggerKind};
+    ///
+    /// let tokens = SAPText::new("Friend ahead faints").tokenize().unwrap();
+    /// let triggers: Vec<EffectTrigger> = tokens.try_into().unwrap();
+    /// assert_eq!(triggers[0].kind().unwrap(), TriggerKind::FriendAheadFaints);
+    /// ```
+    pub fn kind(&self) -> anyhow::Result<TriggerKind> {
+        TriggerKind::try_from(self)
+    }
+
+    /// Get canonical [`TriggerKind`] of trigger and how its occurrences are counted.
+    ///
+    /// ```
+    /// use saplex::{SAPText, EffectTrigger, trigger::{TriggerCount, TriggerKind}};
+    ///
+    /// let tokens = SAPText::new("Every 3 friends summoned").tokenize().unwrap();
+    /// let triggers: Vec<EffectTrigger> = tokens.try_into().unwrap();
+    /// assert_eq!(
+    ///     triggers[0].counted_kind().unwrap(),
+    ///     (TriggerKind::FriendSummoned, TriggerCount::EveryN(3))
+    /// );
+    /// assert!(triggers[0].kind().is_err());
+    /// ```
+    pub fn counted_kind(&self) -> anyhow::Result<(TriggerKind, TriggerCount)> {
+        Ok((self.uncounted_kind()?, self.count))
+    }
+}
+
+/// Distribute fields shared by conjoined triggers to triggers where they were elided.
+/// * Subject and action are shared with later triggers.
+///     * ex. `Friend ahead attacks or is hurt` -> `Friend ahead attacks` and `Friend ahead hurt`
+///     * ex. `Gain perk or ailment` -> `Gain perk` and `Gain ailment`
+/// * Object is shared with earlier triggers.
+///     * ex. `Buy or sell food` -> `Buy food` and `Sell food`
+/// * Triggers with [`EffectTrigger::logic`] are standalone.
+///     * ex. `Start of battle & End turn`
+fn distribute_shared(triggers: &mut [EffectTrigger]) {
+    for i in 1..triggers.len() {
+        let (prev_triggers, next_triggers) = triggers.split_at_mut(i);
+        let (prev, trigger) = (&prev_triggers[i - 1], &mut next_triggers[0]);
+        if trigger.logic.is_some() || prev.logic.is_some() {
+            continue;
+        }
+        let has_subject =
+            trigger.target.is_some() || trigger.prim_pos.is_some() || trigger.sec_pos.is_some();
+        if !has_subject {
+            trigger.target = prev.target;
+            trigger.prim_pos = prev.prim_pos;
+            trigger.sec_pos = prev.sec_pos;
+        }
+        if trigger.action.is_none() {
+            trigger.action = prev.action;
+        }
+    }
+
+    for i in (1..triggers.len()).rev() {
+        let (prev_triggers, next_triggers) = triggers.split_at_mut(i);
+        let (prev, trigger) = (&mut prev_triggers[i - 1], &next_triggers[0]);
+        let (Some(prev_action), Some(action)) = (prev.action, trigger.action) else {
+            continue;
+        };
+        if prev.entity.is_none()
+            && prev.logic.is_none()
+            && trigger.logic.is_none()
+            && prev_action.is_shop_related() == action.is_shop_related()
+        {
+            prev.entity.clone_from(&trigger.entity);
+        }
+    }
+}
+
 impl<'src> TryFrom<SAPTokens<'src>> for Vec<EffectTrigger<'src>> {
     type Error = anyhow::Error;
 
@@ -41,7 +325,11 @@ impl<'src> TryFrom<SAPTokens<'src>> for Vec<EffectTrigger<'src>> {
         while let Some(token) = tokens.next() {
             match token.ttype {
                 TokenType::Numeric(NumericType::Number(Some(num))) => {
-                    trigger.number = Some(num.try_into()?)
+                    let num: usize = num.try_into()?;
+                    trigger.number = Some(num);
+                    if num > 1 {
+                        trigger.count = TriggerCount::Simultaneous(num)
+                    }
                 }
                 TokenType::Entity(ref entity) => {
                     trigger.number = entity.value().and_then(|val| usize::try_from(val).ok());
@@ -49,28 +337,16 @@ impl<'src> TryFrom<SAPTokens<'src>> for Vec<EffectTrigger<'src>> {
                 }
                 TokenType::Position(pos) => trigger.prim_pos = Some(pos),
                 TokenType::Target(target) => trigger.target = Some(target),
-                TokenType::Action(action) => {
-                    if action.is_shop_related() {
-                        trigger.target = Some(TargetType::Shop);
-                    }
-                    trigger.action = Some(action)
-                }
+                TokenType::Action(action) => trigger.action = Some(action),
                 TokenType::Logic(LogicType::And | LogicType::Or) => {
                     // Look at next token.
                     let next_token_type = tokens.peek().map(|token| &token.ttype);
                     match next_token_type {
                         // If new action next or another thing...
                         Some(TokenType::Action(_)) | Some(_) => {
-                            // Create new trigger with same action.
-                            // Swap with old one.
-                            // And push old into triggers.
-                            let mut new_trigger = EffectTrigger {
-                                action: trigger.action,
-                                ..Default::default()
-                            };
-
-                            std::mem::swap(&mut new_trigger, &mut trigger);
-                            triggers.push(new_trigger);
+                            // Create new trigger and push old into triggers.
+                            // Shared fields are distributed after all triggers are created.
+                            triggers.push(std::mem::take(&mut trigger));
                         }
                         None => {
                             bail!(
@@ -80,6 +356,34 @@ impl<'src> TryFrom<SAPTokens<'src>> for Vec<EffectTrigger<'src>> {
                         }
                     }
                 }
+                // ex. Every 3 friends summoned
+                // ex. After 2 hurts
+                TokenType::Logic(logic @ (LogicType::Each | LogicType::After)) => {
+                    let num = tokens.next_if(|token| {
+                        matches!(
+                            token.ttype,
+                            TokenType::Numeric(NumericType::Number(Some(_)))
+                        )
+                    });
+                    match (logic, num.map(|token| &token.ttype)) {
+                        (_, Some(TokenType::Numeric(NumericType::Number(Some(num))))) => {
+                            let num: usize = (*num).try_into()?;
+                            trigger.number = Some(num);
+                            trigger.count = if logic == LogicType::Each {
+                                TriggerCount::EveryN(num)
+                            } else {
+                                TriggerCount::AfterN(num)
+                            };
+                        }
+                        // Each occurrence is the default.
+                        // ex. Each friend summoned
+                        (LogicType::Each, _) => {}
+                        _ => trigger.logic = Some(logic),
+                    }
+                }
+                // Linking verb.
+                // ex. Friend ahead attacks or is hurt
+                TokenType::Logic(LogicType::Is) => {}
                 TokenType::Logic(logic) => trigger.logic = Some(logic),
                 _ => {}
             }
@@ -87,6 +391,19 @@ impl<'src> TryFrom<SAPTokens<'src>> for Vec<EffectTrigger<'src>> {
 
         // Add workhorse trigger.
         triggers.push(trigger);
+        distribute_shared(&mut triggers);
+
+        // Keep explicit target.
+        // ex. Friend sold
+        for trigger in triggers.iter_mut() {
+            if trigger
+                .action
+                .is_some_and(|action| action.is_shop_related())
+                && trigger.target.is_none()
+            {
+                trigger.target = Some(TargetType::Shop);
+            }
+        }
         Ok(triggers)
     }
 }
@@ -102,7 +419,7 @@ mod tests {
     };
     use pretty_assertions::assert_eq;
 
-    use super::EffectTrigger;
+    use super::{EffectTrigger, TriggerCount, TriggerKind};
 
     #[test]
     fn test_interpret_positional_effect_trigger() {
@@ -129,6 +446,7 @@ mod tests {
             [EffectTrigger {
                 action: Some(ActionType::Faint),
                 number: Some(2),
+                count: TriggerCount::Simultaneous(2),
                 target: Some(TargetType::Friend),
                 ..Default::default()
             }]
@@ -144,12 +462,18 @@ mod tests {
         let exp_triggers = [
             EffectTrigger {
                 action: Some(ActionType::Gain),
-                entity: Some(EntityType::Perk(None)),
+                entity: Some(EntityType::Perk {
+                    number: None,
+                    name: None,
+                }),
                 ..Default::default()
             },
             EffectTrigger {
                 action: Some(ActionType::Gain),
-                entity: Some(EntityType::Ailment(None)),
+                entity: Some(EntityType::Ailment {
+                    number: None,
+                    name: None,
+                }),
                 ..Default::default()
             },
         ];
@@ -219,4 +543,194 @@ mod tests {
             }]
         )
     }
+
+    #[test]
+    fn test_interpret_roll_trigger() {
+        let txt = SAPText::new("Roll");
+        let triggers: Vec<EffectTrigger> = txt.tokenize().unwrap().try_into().unwrap();
+
+        assert_eq!(
+            triggers,
+            [EffectTrigger {
+                action: Some(ActionType::Roll),
+                target: Some(TargetType::Shop),
+                ..Default::default()
+            }]
+        )
+    }
+
+    #[test]
+    fn test_trigger_kind() {
+        let texts = [
+            ("Start of battle", TriggerKind::StartOfBattle),
+            ("End turn", TriggerKind::EndTurn),
+            ("Start of turn", TriggerKind::StartOfTurn),
+            ("Before attack", TriggerKind::BeforeAttack),
+            ("Friend ahead faints", TriggerKind::FriendAheadFaints),
+            ("Friend summoned", TriggerKind::FriendSummoned),
+            ("Friend sold", TriggerKind::FriendSold),
+            ("Level-up", TriggerKind::LevelUp),
+            ("Knock out", TriggerKind::KnockOut),
+            ("Eats food", TriggerKind::EatsFood),
+            ("Roll", TriggerKind::Roll),
+        ];
+        for (txt, kind) in texts {
+            let triggers: Vec<EffectTrigger> =
+                SAPText::new(txt).tokenize().unwrap().try_into().unwrap();
+            assert_eq!(triggers[0].kind().unwrap(), kind, "{txt}");
+        }
+
+        // Round-trip all kinds.
+        for kind in TriggerKind::ALL {
+            assert_eq!(
+                TriggerKind::try_from(&EffectTrigger::from(*kind)).unwrap(),
+                *kind
+            );
+        }
+    }
+
+    #[test]
+    fn test_trigger_kind_unrecognized() {
+        let triggers: Vec<EffectTrigger> = SAPText::new("Shop food eaten")
+            .tokenize()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert!(triggers[0].kind().is_err());
+    }
+
+    #[test]
+    fn test_interpret_counted_trigger() {
+        let texts = [
+            ("Friend summoned", None, TriggerCount::Once),
+            ("Each friend summoned", None, TriggerCount::Once),
+            ("Every 3 friends summoned", Some(3), TriggerCount::EveryN(3)),
+            ("After 2 hurts", Some(2), TriggerCount::AfterN(2)),
+            ("Two friends faint", Some(2), TriggerCount::Simultaneous(2)),
+        ];
+        for (txt, number, count) in texts {
+            let triggers: Vec<EffectTrigger> =
+                SAPText::new(txt).tokenize().unwrap().try_into().unwrap();
+            assert_eq!(
+                (triggers[0].number, triggers[0].count),
+                (number, count),
+                "{txt}"
+            );
+        }
+
+        let triggers: Vec<EffectTrigger> = SAPText::new("Every 3 friends summoned")
+            .tokenize()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert!(triggers[0].kind().is_err());
+        assert_eq!(
+            triggers[0].counted_kind().unwrap(),
+            (TriggerKind::FriendSummoned, TriggerCount::EveryN(3))
+        );
+
+        // After with no number is not a counter.
+        let triggers: Vec<EffectTrigger> = SAPText::new("After attack")
+            .tokenize()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(triggers[0].count, TriggerCount::Once);
+        assert_eq!(triggers[0].logic, Some(LogicType::After));
+    }
+
+    #[test]
+    fn test_interpret_elided_subject_trigger() {
+        let txt = SAPText::new("Friend summoned or sold");
+        let triggers: Vec<EffectTrigger> = txt.tokenize().unwrap().try_into().unwrap();
+
+        assert_eq!(
+            triggers,
+            [
+                EffectTrigger {
+                    action: Some(ActionType::Summon),
+                    target: Some(TargetType::Friend),
+                    ..Default::default()
+                },
+                EffectTrigger {
+                    action: Some(ActionType::Sell),
+                    target: Some(TargetType::Friend),
+                    ..Default::default()
+                }
+            ]
+        )
+    }
+
+    #[test]
+    fn test_interpret_elided_subject_position_trigger() {
+        let txt = SAPText::new("Friend ahead attacks or is hurt");
+        let triggers: Vec<EffectTrigger> = txt.tokenize().unwrap().try_into().unwrap();
+
+        assert_eq!(
+            triggers,
+            [
+                EffectTrigger {
+                    action: Some(ActionType::Attack),
+                    target: Some(TargetType::Friend),
+                    prim_pos: Some(PositionType::Ahead),
+                    ..Default::default()
+                },
+                EffectTrigger {
+                    action: Some(ActionType::Hurt),
+                    target: Some(TargetType::Friend),
+                    prim_pos: Some(PositionType::Ahead),
+                    ..Default::default()
+                }
+            ]
+        )
+    }
+
+    #[test]
+    fn test_interpret_elided_object_trigger() {
+        let txt = SAPText::new("Buy or sell food");
+        let triggers: Vec<EffectTrigger> = txt.tokenize().unwrap().try_into().unwrap();
+        let food = EntityType::Food {
+            number: None,
+            name: None,
+        };
+
+        assert_eq!(
+            triggers,
+            [
+                EffectTrigger {
+                    action: Some(ActionType::Buy),
+                    entity: Some(food.clone()),
+                    target: Some(TargetType::Shop),
+                    ..Default::default()
+                },
+                EffectTrigger {
+                    action: Some(ActionType::Sell),
+                    entity: Some(food),
+                    target: Some(TargetType::Shop),
+                    ..Default::default()
+                }
+            ]
+        )
+    }
+
+    #[test]
+    fn test_interpret_standalone_conjoined_trigger() {
+        let txt = SAPText::new("Faint & Start of battle");
+        let triggers: Vec<EffectTrigger> = txt.tokenize().unwrap().try_into().unwrap();
+
+        assert_eq!(
+            triggers,
+            [
+                EffectTrigger {
+                    action: Some(ActionType::Faint),
+                    ..Default::default()
+                },
+                EffectTrigger {
+                    entity: Some(EntityType::Battle(None)),
+                    logic: Some(LogicType::Start),
+                    ..Default::default()
+                }
+            ]
+        )
+    }
 }
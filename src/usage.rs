@@ -0,0 +1,52 @@
+use std::fmt::Display;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Period over which an effect's uses are counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UsagePeriod {
+    /// No period given.
+    /// - ex. `Works once.`
+    #[default]
+    Unspecified,
+    /// Per turn.
+    /// - ex. `Works 1 time per turn.`
+    Turn,
+    /// Per battle.
+    /// - ex. `Works 2 times per battle.`
+    Battle,
+    /// Per game.
+    /// - ex. `Works 1 time per game.`
+    Game,
+    /// Per trigger.
+    /// - ex. `Triggers 3 times.`
+    Trigger,
+}
+
+/// Limit on the number of uses of an effect.
+/// - ex. `Works 2 times per battle.`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UsageLimit {
+    /// Number of uses.
+    pub count: usize,
+    /// Period over which uses are counted.
+    pub period: UsagePeriod,
+}
+
+impl Display for UsageLimit {
+    /// Format limit as in SAP text.
+    /// - ex. `works 2 times per battle`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let count = self.count;
+        match self.period {
+            UsagePeriod::Unspecified => write!(f, "works {count} times"),
+            UsagePeriod::Turn => write!(f, "works {count} times per turn"),
+            UsagePeriod::Battle => write!(f, "works {count} times per battle"),
+            UsagePeriod::Game => write!(f, "works {count} times per game"),
+            UsagePeriod::Trigger => write!(f, "triggers {count} times"),
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use std::{fmt::Display, iter::Peekable};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    compare::{split_stat, Operand},
+    token::{
+        actions::ActionType, attribute::EntityType, logic::LogicType, numeric::NumericType,
+        percent::Percent, position::PositionType, types::TokenType, Token,
+    },
+    trigger::EffectTrigger,
+};
+
+/// A value expression.
+/// - ex. `2x its health`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Value<'src> {
+    /// Literal number.
+    /// - ex. `+2 attack`
+    Literal(i32),
+    /// Percent of a stat of some holder.
+    /// - ex. `50% attack damage`
+    PercentOf {
+        /// Percent of stat.
+        percent: Percent,
+        /// Stat.
+        /// * Inner value is always [`None`].
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        stat: EntityType<'src>,
+        /// Holder of stat.
+        holder: Operand,
+    },
+    /// Value multiplied by some count.
+    /// - ex. `2x its health`
+    Times(Box<Value<'src>>, Box<Value<'src>>),
+    /// Number of items meeting a trigger.
+    /// - ex. `for each gold spent`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    CountOf(EffectTrigger<'src>),
+    /// Sum of values.
+    /// - ex. `the sum of its attack and health`
+    Sum(Vec<Value<'src>>),
+}
+
+/// An amount of some stat.
+/// - ex. `+2 attack`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Amount<'src> {
+    /// Stat.
+    /// * Inner value is always [`None`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub stat: EntityType<'src>,
+    /// Value of stat.
+    pub value: Value<'src>,
+}
+
+impl<'src> Amount<'src> {
+    /// Create [`Amount`] from an [`EntityType`] with some value.
+    /// * ex. `+2 attack` -> `Literal(2)`
+    /// * ex. `50% attack damage` -> `PercentOf(50, attack, this)`
+    ///
+    /// ### Params
+    /// * `entity`
+    ///     * [`EntityType`]
+    /// * `action`
+    ///     * Action of effect.
+    ///     * Percent attack of [`ActionType::Deal`] is damage.
+    ///
+    /// ### Returns
+    /// * [`Amount`] or [`None`] if no value.
+    pub(crate) fn from_entity(
+        entity: &EntityType<'src>,
+        action: Option<ActionType>,
+    ) -> Option<Self> {
+        let (stat, percent) = match entity {
+            EntityType::AttackPercent(Some(perc)) => (EntityType::Attack(None), *perc),
+            EntityType::HealthPercent(Some(perc)) => (EntityType::Health(None), *perc),
+            EntityType::DamagePercent(Some(perc)) => (EntityType::Damage(None), *perc),
+            EntityType::GoldPercent(Some(perc)) => (EntityType::Gold(None), *perc),
+            EntityType::TrumpetPercent(Some(perc)) => (EntityType::Trumpet(None), *perc),
+            entity => {
+                let (stat, value) = split_stat(entity)?;
+                // Only stats that are amounts.
+                if matches!(stat, EntityType::Tier(_) | EntityType::Level(_)) {
+                    return None;
+                }
+                return Some(Amount {
+                    stat,
+                    value: Value::Literal(value?),
+                });
+            }
+        };
+        let amount_stat = if action == Some(ActionType::Deal) {
+            EntityType::Damage(None)
+        } else {
+            stat.clone()
+        };
+        Some(Amount {
+            stat: amount_stat,
+            value: Value::PercentOf {
+                percent,
+                stat,
+                holder: Operand::Position(PositionType::OnSelf),
+            },
+        })
+    }
+
+    /// Scale amount by the number of items meeting a trigger.
+    /// * ex. `+1 attack for each gold spent`
+    pub(crate) fn scale(&mut self, count: &EffectTrigger<'src>) {
+        let value = std::mem::replace(&mut self.value, Value::Literal(0));
+        self.value = Value::Times(Box::new(value), Box::new(Value::CountOf(count.clone())));
+    }
+}
+
+impl<'src> Display for Value<'src> {
+    /// Format value.
+    /// - ex. `2x 100% of triggering health`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Literal(num) => write!(f, "{num}"),
+            Value::PercentOf {
+                percent,
+                stat,
+                holder,
+            } => write!(f, "{percent} of {holder} {stat}"),
+            Value::Times(value, count) => match &**count {
+                Value::CountOf(trigger) => write!(f, "{value} for each {trigger}"),
+                count => write!(f, "{count}x {value}"),
+            },
+            Value::CountOf(trigger) => write!(f, "number of {trigger}"),
+            Value::Sum(values) => {
+                let values: Vec<String> = values.iter().map(ToString::to_string).collect();
+                write!(f, "sum of {}", values.join(" and "))
+            }
+        }
+    }
+}
+
+impl<'src> Display for Amount<'src> {
+    /// Format amount as in SAP text.
+    /// - ex. `2 attack`, `1 attack for each gold`, `attack equal to 2x 100% of triggering health`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let stat = &self.stat;
+        match &self.value {
+            Value::Literal(num) => write!(f, "{num} {stat}"),
+            Value::Times(value, count) => match &**count {
+                Value::CountOf(trigger) => write!(f, "{value} {stat} for each {trigger}"),
+                _ => write!(f, "{stat} equal to {}", self.value),
+            },
+            value => write!(f, "{stat} equal to {value}"),
+        }
+    }
+}
+
+/// Create [`Value`] for a [`NumericType::Equal`] amount.
+/// * This should be invoked when the current [`Token`] has a [`Token::ttype`] of [`NumericType::Equal`].
+/// * ex. `equal to 2x its health`
+/// * ex. `equal to 50% of this's attack`
+/// * ex. `equal to the sum of its attack and health`
+///
+/// ### Params
+/// * `tokens`: [`Peekable`] iterator of tokens.
+/// * `unused`: Consumed tokens not used to create the value.
+///     * ex. A second position or percent.
+///
+/// ### Returns
+/// * [`Value`] or [`None`] if no stat.
+pub(crate) fn create_equal_value<'a, 'src: 'a, T>(
+    tokens: &mut Peekable<T>,
+    unused: &mut Vec<&'a Token<'src>>,
+) -> Option<Value<'src>>
+where
+    T: Iterator<Item = &'a Token<'src>> + Clone,
+{
+    tokens.next_if(|token| token.ttype == TokenType::Logic(LogicType::To));
+
+    let mut factor: Option<i32> = None;
+    let mut percent: Option<Percent> = None;
+    let mut holder: Option<Operand> = None;
+    let mut stats = vec![];
+
+    while let Some(token) = tokens.peek() {
+        match &token.ttype {
+            TokenType::Numeric(
+                NumericType::Multiplier(Some(num)) | NumericType::Number(Some(num)),
+            ) if factor.is_none() && stats.is_empty() => factor = Some(*num),
+            TokenType::Numeric(NumericType::Percent(Some(perc))) if stats.is_empty() => {
+                if percent.is_some() {
+                    unused.push(token)
+                } else {
+                    percent = Some(*perc)
+                }
+            }
+            TokenType::Numeric(NumericType::Sum) if stats.is_empty() => {}
+            TokenType::Position(pos) if stats.is_empty() => {
+                if matches!(holder, Some(Operand::Position(_))) {
+                    unused.push(token)
+                } else {
+                    holder = Some(Operand::Position(*pos))
+                }
+            }
+            TokenType::Target(target) if holder.is_none() && stats.is_empty() => {
+                holder = Some(Operand::Target(*target))
+            }
+            TokenType::Entity(entity) => {
+                let Some((stat, None)) = split_stat(entity) else {
+                    break;
+                };
+                stats.push(stat);
+            }
+            // Multiple stats.
+            // ex. its attack and health
+            TokenType::Logic(LogicType::And) if !stats.is_empty() => {
+                let mut next_tokens = tokens.clone();
+                next_tokens.next();
+                let is_next_stat = next_tokens.peek().is_some_and(|token| {
+                    matches!(&token.ttype, TokenType::Entity(entity) if matches!(split_stat(entity), Some((_, None))))
+                });
+                if !is_next_stat {
+                    break;
+                }
+            }
+            _ => break,
+        }
+        tokens.next();
+    }
+
+    let holder = holder.unwrap_or(Operand::Position(PositionType::OnSelf));
+    let mut values: Vec<Value> = stats
+        .into_iter()
+        .map(|stat| Value::PercentOf {
+            percent: percent.unwrap_or(Percent::new(100)),
+            stat,
+            holder,
+        })
+        .collect();
+    let value = match values.len() {
+        0 => return None,
+        1 => values.remove(0),
+        _ => Value::Sum(values),
+    };
+    Some(match factor {
+        Some(factor) => Value::Times(Box::new(value), Box::new(Value::Literal(factor))),
+        None => value,
+    })
+}